@@ -0,0 +1,101 @@
+//! Benchmarks for every day, timing parsing separately from each part.
+//!
+//! Each day is benchmarked on its input in `inputs/`, if there is one, and on a generated input
+//! about as big as a real one under `<day>/generated`. To compare against an earlier run, save a
+//! baseline with `cargo bench -- --save-baseline <name>` and then run
+//! `cargo bench -- --baseline <name>`.
+
+use std::hint::black_box;
+
+use aoc_2023::{
+    days::{
+        day1::{self, Day1},
+        day1_const::Day1Const,
+        day2::Day2,
+        day4::Day4,
+        day6, day7,
+    },
+    generate,
+    input::InputSource,
+    Solution,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkGroup, Criterion};
+use winnow::Parser;
+
+type Group<'a> = BenchmarkGroup<'a, criterion::measurement::WallTime>;
+
+/// Benchmarks `S::parse`, `S::part1` and `S::part2` for `day`, plus anything `extra` adds to the
+/// group, on the day's input and on a generated one.
+fn bench_solution<S: Solution>(
+    c: &mut Criterion,
+    name: &str,
+    day: u32,
+    extra: impl Fn(&mut Group, &str),
+) {
+    match InputSource::Default.read(day) {
+        Ok(input) => bench_input::<S>(c, name, &input, &extra),
+        Err(e) => eprintln!("Skipping {}: {:#}", name, e),
+    }
+    if let Some(generator) = generate::generator(day) {
+        let input = generator.generate(0, generator.default_size);
+        bench_input::<S>(c, &format!("{}/generated", name), &input, &extra);
+    }
+}
+
+fn bench_input<S: Solution>(
+    c: &mut Criterion,
+    name: &str,
+    input: &str,
+    extra: impl Fn(&mut Group, &str),
+) {
+    let mut group = c.benchmark_group(name);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input)).unwrap()));
+    let parsed = S::parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter(|| S::part1(black_box(&parsed)).unwrap())
+    });
+    group.bench_function("part2", |b| {
+        b.iter(|| S::part2(black_box(&parsed)).unwrap())
+    });
+    extra(&mut group, input);
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_solution::<Day1>(c, "day1", 1, |group, input| {
+        group.bench_function("part1_scalar", |b| {
+            b.iter(|| day1::part1_scalar(black_box(input)).unwrap())
+        });
+        group.bench_function("part1_swar", |b| {
+            b.iter(|| day1::part1_swar(black_box(input)).unwrap())
+        });
+        group.bench_function("part2_scan", |b| {
+            b.iter(|| day1::part2_scan(black_box(input)).unwrap())
+        });
+        group.bench_function("part2_read", |b| {
+            b.iter(|| day1::part2_read(black_box(input.as_bytes())).unwrap())
+        });
+    });
+    bench_solution::<Day1Const>(c, "day1_const", 1, |_, _| {});
+    bench_solution::<Day2>(c, "day2", 2, |_, _| {});
+    bench_solution::<Day4>(c, "day4", 4, |_, _| {});
+    bench_solution::<day6::Day6>(c, "day6", 6, |group, input| {
+        group.bench_function("game_parser_part_1", |b| {
+            b.iter(|| day6::game_parser_part_1.parse(black_box(input)).unwrap())
+        });
+        group.bench_function("game_parser_part_2", |b| {
+            b.iter(|| day6::game_parser_part_2.parse(black_box(input)).unwrap())
+        });
+    });
+    bench_solution::<day7::Day7>(c, "day7", 7, |group, input| {
+        group.bench_function("Game::parse_part1", |b| {
+            b.iter(|| day7::Game::parse_part1.parse(black_box(input)).unwrap())
+        });
+        group.bench_function("Game::parse_part2", |b| {
+            b.iter(|| day7::Game::parse_part2.parse(black_box(input)).unwrap())
+        });
+    });
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,77 @@
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    day1_const_input(&manifest_dir);
+    example_tests(&manifest_dir, &out_dir);
+}
+
+/// `day1_const` can solve an input at compile time, so it needs the input path before the crate is
+/// built. Solving it slows every build down, so it's only done for an input named by
+/// `AOC_DAY1_CONST_INPUT`. Without one, or if there's no file there, the compile-time answers are
+/// left out.
+fn day1_const_input(manifest_dir: &Path) {
+    println!("cargo:rerun-if-env-changed=AOC_DAY1_CONST_INPUT");
+    println!("cargo:rustc-check-cfg=cfg(day1_const_input)");
+
+    let Some(path) = env::var_os("AOC_DAY1_CONST_INPUT") else {
+        return;
+    };
+    let path = manifest_dir.join(path);
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    if path.is_file() {
+        println!("cargo:rustc-cfg=day1_const_input");
+        println!("cargo:rustc-env=AOC_DAY1_CONST_INPUT={}", path.display());
+    }
+}
+
+/// Writes a test for every part with a recorded answer in `examples/dayNN/answers.toml`, so that
+/// adding an example only needs the example file and its answers.
+fn example_tests(manifest_dir: &Path, out_dir: &Path) {
+    let examples = manifest_dir.join("examples");
+    println!("cargo:rerun-if-changed={}", examples.display());
+
+    let mut dirs: Vec<PathBuf> = fs::read_dir(&examples)
+        .map(|entries| entries.filter_map(|e| Some(e.ok()?.path())).collect())
+        .unwrap_or_default();
+    dirs.retain(|dir| dir.join("answers.toml").is_file());
+    dirs.sort();
+
+    let mut tests = String::new();
+    for dir in dirs {
+        let answers_path = dir.join("answers.toml");
+        println!("cargo:rerun-if-changed={}", dir.display());
+        println!("cargo:rerun-if-changed={}", answers_path.display());
+
+        let contents = fs::read_to_string(&answers_path).unwrap();
+        let answers: toml::Table = toml::from_str(&contents)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", answers_path.display(), e));
+        let dir_name = dir.file_name().unwrap().to_str().unwrap();
+
+        for (file_name, entry) in answers {
+            for (key, part) in [("part1", "One"), ("part2", "Two")] {
+                if entry.get(key).is_none() {
+                    continue;
+                }
+                let stem = file_name.rsplit_once('.').map_or(&*file_name, |(x, _)| x);
+                let test_name = format!("{}_{}_{}", dir_name, stem, key)
+                    .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+                writeln!(
+                    tests,
+                    "#[test]\nfn {}() {{\n    check(\"{}\", \"{}\", Part::{});\n}}\n",
+                    test_name, dir_name, file_name, part
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    fs::write(out_dir.join("example_tests.rs"), tests).unwrap();
+}
@@ -0,0 +1,284 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::{eyre::Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{days::SOLVERS, runner::Part};
+
+/// The verified answers for each input file in a directory, keyed by file name.
+///
+/// ```toml
+/// ["day01.txt"]
+/// day = 1
+/// part1 = "54630"
+/// part2 = "54770"
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    pub inputs: BTreeMap<String, Entry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Entry {
+    pub day: u32,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Entry {
+    pub fn new(day: u32) -> Self {
+        Entry {
+            day,
+            part1: None,
+            part2: None,
+        }
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: &str) {
+        let answer = Some(answer.to_string());
+        match part {
+            Part::One => self.part1 = answer,
+            Part::Two => self.part2 = answer,
+        }
+    }
+}
+
+impl Answers {
+    pub const FILE_NAME: &'static str = "answers.toml";
+
+    /// Reads the answers recorded in `dir`, or no answers if there's no answers file.
+    pub fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(Self::FILE_NAME);
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let contents = fs::read_to_string(&path)
+            .wrap_err_with(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&contents).wrap_err_with(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<()> {
+        let path = dir.join(Self::FILE_NAME);
+        let contents = toml::to_string(self).wrap_err("Failed to serialize answers")?;
+        fs::write(&path, contents).wrap_err_with(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+/// The outcome of running one solver on one recorded input.
+#[derive(Debug)]
+pub struct Check {
+    pub input: PathBuf,
+    pub solver: &'static str,
+    pub part: Part,
+    /// The recorded answer, if there is one.
+    pub expected: Option<String>,
+    /// The solver's answer, or the error it failed with.
+    pub actual: Result<String, String>,
+}
+
+impl Check {
+    /// Whether the solver succeeded, with the recorded answer if there is one.
+    pub fn passed(&self) -> bool {
+        match (&self.expected, &self.actual) {
+            (Some(expected), Ok(actual)) => actual == expected,
+            (None, Ok(_)) => true,
+            (_, Err(_)) => false,
+        }
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} on {} part {}: ",
+            self.solver,
+            self.input.display(),
+            self.part,
+        )?;
+        if let Some(expected) = &self.expected {
+            write!(f, "expected {}, ", expected)?;
+        }
+        match &self.actual {
+            Ok(actual) => write!(f, "got {}", actual),
+            Err(e) => write!(f, "failed with {}", e),
+        }
+    }
+}
+
+/// Runs every registered solver against every input recorded in `dir`'s answers file.
+pub fn verify(dir: &Path) -> Result<Vec<Check>> {
+    let answers = Answers::load(dir)?;
+    let mut checks = Vec::new();
+    for (file_name, entry) in &answers.inputs {
+        let parts: Vec<Part> = Part::ALL
+            .into_iter()
+            .filter(|part| entry.get(*part).is_some())
+            .collect();
+        checks.extend(check_input(
+            &dir.join(file_name),
+            entry.day,
+            &parts,
+            Some(entry),
+        ));
+    }
+    Ok(checks)
+}
+
+/// Runs `day`'s solvers on every input file in `dir`, checking each answer against the one
+/// recorded in `dir`'s answers file if there is one. Inputs recorded for other days are skipped.
+pub fn compare(dir: &Path, day: u32, parts: &[Part]) -> Result<Vec<Check>> {
+    let answers = Answers::load(dir)?;
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir).wrap_err_with(|| format!("Failed to read {}", dir.display()))? {
+        let path = entry?.path();
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        if path.is_file() && file_name != Answers::FILE_NAME && !file_name.starts_with('.') {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut checks = Vec::new();
+    for path in paths {
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        let entry = answers.inputs.get(file_name.as_ref());
+        if entry.is_some_and(|entry| entry.day != day) {
+            continue;
+        }
+        checks.extend(check_input(&path, day, parts, entry));
+    }
+    Ok(checks)
+}
+
+/// Runs `day`'s solvers on the input at `path`, checking against `entry`'s answers.
+fn check_input(path: &Path, day: u32, parts: &[Part], entry: Option<&Entry>) -> Vec<Check> {
+    let input = fs::read_to_string(path)
+        .wrap_err_with(|| format!("Failed to read input {}", path.display()))
+        .map_err(|e| format!("{:#}", e));
+
+    let mut checks = Vec::new();
+    for solver in SOLVERS.iter().filter(|solver| solver.day == day) {
+        for &part in parts {
+            // each part runs on its own, so that one failing doesn't hide the other's answer
+            let actual = input.clone().and_then(|input| {
+                solver
+                    .run(&input, &[part])
+                    .map(|mut answers| answers.remove(0))
+                    .map_err(|e| format!("{:#}", e))
+            });
+            checks.push(Check {
+                input: path.to_path_buf(),
+                solver: solver.name,
+                part,
+                expected: entry.and_then(|entry| entry.get(part)).map(String::from),
+                actual,
+            });
+        }
+    }
+    checks
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_recorded_answers() -> Result<()> {
+        let failures: Vec<String> = verify(Path::new("inputs"))?
+            .into_iter()
+            .filter(|check| !check.passed())
+            .map(|check| check.to_string())
+            .collect();
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_answers_round_trip() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut answers = Answers::load(dir.path())?;
+        assert!(answers.inputs.is_empty());
+
+        let mut entry = Entry::new(6);
+        entry.set(Part::One, "288");
+        answers
+            .inputs
+            .insert("example.txt".to_string(), entry.clone());
+        answers.save(dir.path())?;
+
+        let answers = Answers::load(dir.path())?;
+        assert_eq!(answers.inputs.get("example.txt"), Some(&entry));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_reports_mismatches() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(
+            dir.path().join("races.txt"),
+            "Time:      7  15   30\nDistance:  9  40  200\n",
+        )?;
+        fs::write(
+            dir.path().join(Answers::FILE_NAME),
+            "[\"races.txt\"]\nday = 6\npart1 = \"288\"\npart2 = \"1\"\n",
+        )?;
+
+        let checks = verify(dir.path())?;
+        assert_eq!(checks.len(), 2);
+        assert!(checks[0].passed());
+        assert!(!checks[1].passed());
+        assert_eq!(checks[1].actual.as_deref(), Ok("71503"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare_inputs() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let cards = |ids: [u32; 2]| {
+            ids.map(|id| format!("Card {}: 41 48 | 83 41\n", id))
+                .concat()
+        };
+        fs::write(dir.path().join("alice.txt"), cards([1, 2]))?;
+        fs::write(dir.path().join("bob.txt"), cards([1, 3]))?;
+        fs::write(dir.path().join("carol.txt"), cards([1, 2]))?;
+        fs::write(
+            dir.path().join(Answers::FILE_NAME),
+            "[\"alice.txt\"]\nday = 4\npart2 = \"3\"\n\
+             [\"carol.txt\"]\nday = 4\npart2 = \"4\"\n\
+             [\"races.txt\"]\nday = 6\npart1 = \"288\"\n",
+        )?;
+        fs::write(dir.path().join("races.txt"), "")?;
+
+        let checks = compare(dir.path(), 4, &[Part::Two])?;
+        let results: Vec<_> = checks
+            .iter()
+            .map(|check| (check.input.file_name().unwrap(), check.passed()))
+            .collect();
+        assert_eq!(
+            results,
+            [
+                ("alice.txt".as_ref(), true),
+                ("bob.txt".as_ref(), false),
+                ("carol.txt".as_ref(), false)
+            ]
+        );
+        // bob's cards aren't numbered in order, and nobody recorded an answer for them
+        assert_eq!(checks[1].expected, None);
+        assert!(checks[1].actual.is_err());
+        Ok(())
+    }
+}
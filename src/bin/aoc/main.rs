@@ -0,0 +1,797 @@
+use std::{
+    fs,
+    num::NonZeroUsize,
+    panic,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use aoc_2023::{
+    answers::{self, Answers, Check, Entry},
+    client::{Client, Fetched},
+    config::Config,
+    days::{
+        day1::{self, Dictionary},
+        SOLVERS,
+    },
+    diagnostic::Mode,
+    diff,
+    error::{Error, ErrorKind},
+    generate,
+    input::{self, InputSource},
+    measure::{CountingAllocator, Measurement},
+    runner::{Part, Run, Solver},
+    scaffold,
+    submit::{History, Verdict},
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use color_eyre::{
+    eyre::{bail, eyre},
+    Report, Result,
+};
+use serde::Serialize;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solvers for one day, or for every registered day
+    Run {
+        /// The day to run, or `all`
+        day: Days,
+
+        /// Only run this part
+        #[arg(long)]
+        part: Option<Part>,
+
+        /// Read the input from this file, or from stdin if `-` [default: inputs/dayNN.txt]
+        #[arg(long)]
+        input: Option<InputSource>,
+
+        /// Run on every input in this directory instead, checking against its answers.toml
+        #[arg(
+            long,
+            conflicts_with_all = ["input", "time", "lenient", "format", "jobs"]
+        )]
+        inputs: Option<PathBuf>,
+
+        /// Report the time taken and memory allocated by parsing and by each part
+        #[arg(long)]
+        time: bool,
+
+        /// Warn about input left over after parsing, instead of failing
+        #[arg(long)]
+        lenient: bool,
+
+        /// How to print the results
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+
+        /// How many solvers to run at once; use 1 for the least noisy timings [default: one per
+        /// CPU]
+        #[arg(long)]
+        jobs: Option<NonZeroUsize>,
+
+        /// Show which digits day 1 found on each line containing FILTER, or on every line
+        #[arg(
+            long,
+            value_name = "FILTER",
+            num_args = 0..=1,
+            default_missing_value = "",
+            conflicts_with_all = ["inputs", "time", "lenient", "format", "jobs"]
+        )]
+        explain: Option<String>,
+    },
+    /// Download a day's input to inputs/dayNN.txt, unless it's already there
+    Fetch { day: u32 },
+    /// Solve one part of a day and submit the answer, unless it's known to be wrong
+    Submit { day: u32, part: Part },
+    /// Create and register a stub solution for a new day, with an example to fill in
+    New { day: u32 },
+    /// Run every solver registered for a day on the same inputs, and report where they disagree
+    Diff {
+        day: u32,
+
+        /// How many generated inputs to try, as well as the day's input and examples
+        #[arg(long, default_value_t = 100)]
+        generated: u64,
+
+        /// The seed of the first generated input; the rest use the seeds after it
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// How big each generated input is, as for `aoc gen`
+        #[arg(long, default_value_t = 20)]
+        size: usize,
+    },
+    /// Print a random input for a day, e.g. to stress a solver with `aoc run <day> --input -`
+    Gen {
+        day: u32,
+
+        /// How big an input to make; what this counts depends on the day [default: about the
+        /// size of a real input]
+        #[arg(long)]
+        size: Option<usize>,
+
+        /// The same seed and size always give the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Check every registered solver against the answers recorded for each input
+    Verify {
+        /// The directory holding the inputs and their answers.toml
+        #[arg(long, default_value = "inputs")]
+        dir: PathBuf,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Days {
+    All,
+    Day(u32),
+}
+
+impl Days {
+    fn solvers(self) -> Result<Vec<&'static Solver>> {
+        let solvers: Vec<_> = SOLVERS
+            .iter()
+            .filter(|solver| match self {
+                Days::All => true,
+                Days::Day(day) => solver.day == day,
+            })
+            .collect();
+        match self {
+            Days::Day(day) if solvers.is_empty() => bail!("No solver registered for day {}", day),
+            _ => Ok(solvers),
+        }
+    }
+}
+
+impl FromStr for Days {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s == "all" {
+            Ok(Days::All)
+        } else {
+            s.parse()
+                .map(Days::Day)
+                .map_err(|_| eyre!("Invalid day {}, expected a number or `all`", s))
+        }
+    }
+}
+
+fn run(
+    days: Days,
+    part: Option<Part>,
+    input: Option<InputSource>,
+    time: bool,
+    mode: Mode,
+    format: Format,
+    jobs: usize,
+) -> Result<()> {
+    if matches!(days, Days::All) && input.is_some() {
+        bail!("--input can only be used when running a single day");
+    }
+    let source = input.unwrap_or_default();
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    // every solver for a day shares one input, which may only be readable once (e.g. stdin)
+    let inputs: Vec<_> = days
+        .solvers()?
+        .chunk_by(|x, y| x.day == y.day)
+        .map(|solvers| (solvers.to_vec(), source.read(solvers[0].day)))
+        .collect();
+    let tasks: Vec<_> = inputs
+        .iter()
+        .flat_map(|(solvers, input)| solvers.iter().map(move |&solver| (solver, input)))
+        .collect();
+
+    let start = Instant::now();
+    let (sender, receiver) = mpsc::channel();
+    let next = AtomicUsize::new(0);
+    let mut outcomes: Vec<Option<Outcome>> = tasks.iter().map(|_| None).collect();
+    // a panic becomes its solver's error, so don't also print a report for it, from every worker
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let ran = thread::scope(|scope| -> Result<()> {
+        for _ in 0..jobs.min(tasks.len()) {
+            let (sender, next, tasks, parts) = (sender.clone(), &next, &tasks, &parts);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&(solver, input)) = tasks.get(i) else {
+                    break;
+                };
+                let outcome = run_solver(solver, input, parts, mode, days);
+                if sender.send((i, outcome)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        for (i, outcome) in receiver {
+            if format == Format::JsonLines {
+                for record in outcome.records(&parts) {
+                    println!("{}", serde_json::to_string(&record)?);
+                }
+            }
+            outcomes[i] = Some(outcome);
+        }
+        Ok(())
+    });
+    panic::set_hook(hook);
+    ran?;
+    let outcomes: Vec<Outcome> = outcomes.into_iter().flatten().collect();
+
+    match format {
+        Format::Table => print_outcomes(&outcomes, &parts, time, start.elapsed()),
+        Format::Json => {
+            let records: Vec<_> = outcomes.iter().flat_map(|x| x.records(&parts)).collect();
+            println!("{}", serde_json::to_string_pretty(&records)?);
+        }
+        Format::JsonLines => {}
+    }
+
+    let failures: usize = outcomes
+        .iter()
+        .map(|x| match &x.result {
+            Ok(run) => run.answers.iter().filter(|x| x.value.is_err()).count(),
+            Err(Failure::Error(_)) => parts.len(),
+            Err(Failure::Skipped(_)) => 0,
+        })
+        .sum();
+    if failures > 0 {
+        bail!("{} part(s) failed", failures);
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// A table, for reading in a terminal
+    Table,
+    /// A JSON array with an object per day and part, once every solver has run
+    Json,
+    /// A JSON object per day and part on each line, printed as soon as each solver finishes
+    JsonLines,
+}
+
+/// What happened when a solver ran on its day's input.
+struct Outcome {
+    solver: &'static Solver,
+    input_hash: Option<String>,
+    result: Result<Run, Failure>,
+}
+
+enum Failure {
+    /// There was no input to run on.
+    Skipped(String),
+    Error(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    Ok,
+    Error,
+    Skipped,
+}
+
+/// One day and part of a run, as printed by `--format json`. Times are in nanoseconds, and
+/// `allocations` and `bytes` are for solving the part, not parsing.
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u32,
+    solver: &'a str,
+    part: Part,
+    status: Status,
+    answer: Option<&'a str>,
+    error: Option<String>,
+    input_hash: Option<&'a str>,
+    parse_ns: Option<u128>,
+    part_ns: Option<u128>,
+    allocations: Option<u64>,
+    bytes: Option<u64>,
+    warnings: Vec<String>,
+}
+
+impl Outcome {
+    fn records(&self, parts: &[Part]) -> Vec<Record<'_>> {
+        let record = |part, status| Record {
+            day: self.solver.day,
+            solver: self.solver.name,
+            part,
+            status,
+            answer: None,
+            error: None,
+            input_hash: self.input_hash.as_deref(),
+            parse_ns: None,
+            part_ns: None,
+            allocations: None,
+            bytes: None,
+            warnings: Vec::new(),
+        };
+        match &self.result {
+            Ok(run) => run
+                .answers
+                .iter()
+                .map(|answer| Record {
+                    answer: answer.value.as_deref().ok(),
+                    error: answer.value.as_ref().err().map(|e| format!("{:#}", e)),
+                    parse_ns: Some(run.parse.elapsed.as_nanos()),
+                    part_ns: Some(answer.measurement.elapsed.as_nanos()),
+                    allocations: Some(answer.measurement.allocations),
+                    bytes: Some(answer.measurement.bytes),
+                    warnings: run.warnings.iter().map(ToString::to_string).collect(),
+                    ..record(
+                        answer.part,
+                        if answer.value.is_ok() {
+                            Status::Ok
+                        } else {
+                            Status::Error
+                        },
+                    )
+                })
+                .collect(),
+            Err(failure) => {
+                let (status, error) = match failure {
+                    Failure::Skipped(e) => (Status::Skipped, e),
+                    Failure::Error(e) => (Status::Error, e),
+                };
+                parts
+                    .iter()
+                    .map(|&part| Record {
+                        error: Some(error.clone()),
+                        ..record(part, status)
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Prints a summary table with a row per day and part, in the order the solvers are registered.
+fn print_outcomes(outcomes: &[Outcome], parts: &[Part], time: bool, elapsed: Duration) {
+    let mut header = vec!["Day", "Solver", "Part", "Answer", "Time", "Status"];
+    if time {
+        header.extend(["Allocs", "Bytes"]);
+    }
+    let mut rows = vec![header.into_iter().map(String::from).collect()];
+    let mut errors = Vec::new();
+    let mut warnings = Vec::new();
+    for outcome in outcomes {
+        let solver = outcome.solver;
+        let row = |part: &str, answer: String, measurement: Option<Measurement>, status: &str| {
+            let [elapsed, allocations, bytes] = measurement_cells(measurement);
+            let mut row = vec![
+                solver.day.to_string(),
+                solver.name.to_string(),
+                part.to_string(),
+                answer,
+                elapsed,
+                status.to_string(),
+            ];
+            if time {
+                row.extend([allocations, bytes]);
+            }
+            row
+        };
+
+        match &outcome.result {
+            Ok(run) => {
+                if time {
+                    rows.push(row("parse", String::new(), Some(run.parse), ""));
+                }
+                for answer in &run.answers {
+                    let part = answer.part.to_string();
+                    let measurement = Some(answer.measurement);
+                    match &answer.value {
+                        Ok(value) => rows.push(row(&part, value.clone(), measurement, "ok")),
+                        Err(e) => {
+                            let e = format!("{:#}", e);
+                            rows.push(row(&part, error_cell(&e), measurement, "FAILED"));
+                            errors.push((solver.name, e));
+                        }
+                    }
+                }
+                warnings.extend(run.warnings.iter().map(|w| (solver.name, w)));
+            }
+            Err(Failure::Skipped(_)) => {
+                for part in parts {
+                    rows.push(row(&part.to_string(), "no input".into(), None, "skipped"));
+                }
+            }
+            Err(Failure::Error(e)) => {
+                for part in parts {
+                    rows.push(row(&part.to_string(), error_cell(e), None, "FAILED"));
+                }
+                errors.push((solver.name, e.clone()));
+            }
+        }
+    }
+    print_table(&rows);
+    print_errors(&errors);
+    for (name, warning) in warnings {
+        println!("\n{}: warning: {}", name, warning);
+    }
+    println!("\nRan {} solver(s) in {:.2?}", outcomes.len(), elapsed);
+}
+
+/// Runs one solver, turning its errors into a [`Failure`] for it alone. Panics are caught by the
+/// runner, and fail just the part that panicked.
+fn run_solver(
+    solver: &'static Solver,
+    input: &Result<String>,
+    parts: &[Part],
+    mode: Mode,
+    days: Days,
+) -> Outcome {
+    let result = match input {
+        Ok(input) => solver
+            .measure(input, parts, mode)
+            .map_err(|e| Failure::Error(format!("{:#}", e))),
+        // days without an input are expected when running everything
+        Err(e) if matches!(days, Days::All) && is_missing_input(e) => {
+            Err(Failure::Skipped(format!("{:#}", e)))
+        }
+        Err(e) => Err(Failure::Error(format!("{:#}", e))),
+    };
+    Outcome {
+        solver,
+        input_hash: input.as_ref().ok().map(|input| input::hash(input)),
+        result,
+    }
+}
+
+fn is_missing_input(error: &Report) -> bool {
+    matches!(
+        Error::find(error).map(|e| &e.kind),
+        Some(ErrorKind::MissingInput(_))
+    )
+}
+
+/// The first line of an error, to keep the table readable. The rest is printed by
+/// [`print_errors`].
+fn error_cell(error: &str) -> String {
+    format!("error: {}", error.lines().next().unwrap_or_default())
+}
+
+/// Prints the full text of each error that was cut short in a table.
+fn print_errors(errors: &[(&str, String)]) {
+    for (name, error) in errors {
+        if error.contains('\n') {
+            println!("\n{}: {}", name, error);
+        }
+    }
+}
+
+fn measurement_cells(measurement: Option<Measurement>) -> [String; 3] {
+    match measurement {
+        Some(measurement) => [
+            format!("{:.2?}", measurement.elapsed),
+            measurement.allocations.to_string(),
+            measurement.bytes.to_string(),
+        ],
+        None => Default::default(),
+    }
+}
+
+fn fetch(day: u32) -> Result<()> {
+    let client = Client::from_config(&Config::load()?);
+    let path = input::default_path(day);
+    match client.fetch_input(day, &path)? {
+        Fetched::Cached => println!("Using cached input {}", path.display()),
+        Fetched::Downloaded => println!("Downloaded input to {}", path.display()),
+    }
+    Ok(())
+}
+
+fn submit(day: u32, part: Part) -> Result<()> {
+    let solver = Days::Day(day).solvers()?[0];
+    let input = InputSource::Default.read(day)?;
+    let answer = solver.run(&input, &[part])?.remove(0);
+
+    let history_path = Path::new(History::PATH);
+    let mut history = History::load(history_path)?;
+    if let Some(rejection) = history.check(day, part, &answer, SystemTime::now()) {
+        bail!("Not submitting {}: {}", answer, rejection);
+    }
+
+    let client = Client::from_config(&Config::load()?);
+    let verdict = client.submit_answer(day, part, &answer)?;
+    history.record(day, part, &answer, verdict, SystemTime::now());
+    history.save(history_path)?;
+
+    println!("Day {} part {}: {} is {}", day, part, answer, verdict);
+    if verdict != Verdict::Correct {
+        bail!("Answer was not accepted");
+    }
+
+    // remember the verified answer, so `aoc verify` can check future changes against it
+    let path = input::default_path(day);
+    let (dir, file_name) = (path.parent().unwrap(), path.file_name().unwrap());
+    let mut answers = Answers::load(dir)?;
+    answers
+        .inputs
+        .entry(file_name.to_string_lossy().into_owned())
+        .or_insert_with(|| Entry::new(day))
+        .set(part, &answer);
+    answers.save(dir)
+}
+
+fn new(day: u32) -> Result<()> {
+    scaffold::new_day(Path::new("."), day)?;
+    println!("Created src/days/day{}.rs and examples/day{:02}/", day, day);
+    println!("Add the puzzle's example to examples/day{:02}/example.txt, and its answers to answers.toml", day);
+    Ok(())
+}
+
+fn diff(day: u32, generated: u64, seed: u64, size: usize) -> Result<()> {
+    let solvers = Days::Day(day).solvers()?;
+    if solvers.len() < 2 {
+        bail!(
+            "Only {} is registered for day {}, so there's nothing to compare",
+            solvers[0].name,
+            day
+        );
+    }
+
+    let mut inputs = Vec::new();
+    let path = input::default_path(day);
+    if path.exists() {
+        inputs.push((path.display().to_string(), InputSource::Default.read(day)?));
+    }
+    let examples = Path::new("examples").join(format!("day{:02}", day));
+    if examples.is_dir() {
+        let mut paths: Vec<_> = fs::read_dir(&examples)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<_, _>>()?;
+        paths.retain(|path| path.extension().is_some_and(|x| x == "txt"));
+        paths.sort();
+        for path in paths {
+            let input = InputSource::Path(path.clone()).read(day)?;
+            inputs.push((path.display().to_string(), input));
+        }
+    }
+    for seed in (seed..).take(generated as usize) {
+        if let Some(input) = generate::generate(day, seed, size) {
+            inputs.push((format!("generated (seed {})", seed), input));
+        }
+    }
+
+    let names: Vec<_> = solvers.iter().map(|solver| solver.name).collect();
+    println!(
+        "Comparing {} on {} input(s)",
+        names.join(", "),
+        inputs.len()
+    );
+    let mut disagreements = 0;
+    for (name, input) in &inputs {
+        for part in Part::ALL {
+            let Some(disagreement) = diff::diff(&solvers, input, part) else {
+                continue;
+            };
+            disagreements += 1;
+            println!("\n{} part {}: solvers disagree", name, part);
+            print_results(&disagreement.results);
+            if let Some(line) = disagreement.line {
+                println!("  first at line {}: {}", line.number, line.text);
+                print_results(&line.results);
+            }
+        }
+    }
+
+    if disagreements > 0 {
+        bail!("{} disagreement(s)", disagreements);
+    }
+    println!("All solvers agree");
+    Ok(())
+}
+
+fn print_results(results: &diff::Results) {
+    for (name, answer) in results {
+        match answer {
+            Ok(answer) => println!("    {}: {}", name, answer),
+            Err(e) => println!("    {}: {}", name, error_cell(e)),
+        }
+    }
+}
+
+fn gen(day: u32, size: Option<usize>, seed: u64) -> Result<()> {
+    let Some(generator) = generate::generator(day) else {
+        bail!("No generator for day {}", day);
+    };
+    let size = size.unwrap_or(generator.default_size);
+    eprintln!(
+        "Generated day {} with {} {} and seed {}",
+        day, size, generator.size, seed
+    );
+    print!("{}", generator.generate(seed, size));
+    Ok(())
+}
+
+fn verify(dir: &Path) -> Result<()> {
+    print_checks(answers::verify(dir)?)
+}
+
+fn compare(days: Days, part: Option<Part>, dir: &Path) -> Result<()> {
+    let Days::Day(day) = days else {
+        bail!("--inputs can only be used when running a single day");
+    };
+    days.solvers()?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    print_checks(answers::compare(dir, day, &parts)?)
+}
+
+/// Prints how day 1 decoded each line containing `filter`, and the answer for the whole input.
+fn explain(days: Days, part: Option<Part>, input: Option<InputSource>, filter: &str) -> Result<()> {
+    if !matches!(days, Days::Day(1)) {
+        bail!("--explain only works for day 1");
+    }
+    let input = input.unwrap_or_default().read(1)?;
+    let input = input::normalize(&input);
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let mut failures = 0;
+    for part in parts {
+        let dictionary = match part {
+            Part::One => Dictionary::digits(),
+            Part::Two => Dictionary::english(),
+        };
+        println!("Part {}\n", part);
+        let (mut shown, mut total) = (0, Some(0u32));
+        for calibration in day1::calibrations(&input, dictionary) {
+            match calibration {
+                Ok(calibration) => {
+                    total = total.and_then(|total| total.checked_add(calibration.value));
+                    if calibration.line.contains(filter) {
+                        println!("{}", calibration);
+                        shown += 1;
+                    }
+                }
+                // lines that couldn't be decoded are shown whatever the filter
+                Err(e) => {
+                    println!("{}\n", e);
+                    failures += 1;
+                    total = None;
+                }
+            }
+        }
+        let answer = total.map_or_else(|| "unknown".to_string(), |total| total.to_string());
+        println!("Showed {} line(s); part {} is {}\n", shown, part, answer);
+    }
+    if failures > 0 {
+        bail!("{} line(s) couldn't be decoded", failures);
+    }
+    Ok(())
+}
+
+/// Prints a table of checks, flagging any that errored or didn't match their recorded answer.
+fn print_checks(checks: Vec<Check>) -> Result<()> {
+    let mut rows = vec![["Input", "Solver", "Part", "Expected", "Actual", "Status"]
+        .into_iter()
+        .map(String::from)
+        .collect()];
+    let mut errors = Vec::new();
+    let mut failures = 0;
+    for check in checks {
+        let status = match (check.passed(), &check.actual) {
+            (true, _) => "ok",
+            (false, Ok(_)) => "MISMATCH",
+            (false, Err(_)) => "FAILED",
+        };
+        if !check.passed() {
+            failures += 1;
+        }
+        rows.push(vec![
+            check.input.display().to_string(),
+            check.solver.to_string(),
+            check.part.to_string(),
+            check.expected.unwrap_or_else(|| "-".to_string()),
+            match check.actual {
+                Ok(actual) => actual,
+                Err(e) => {
+                    let cell = error_cell(&e);
+                    errors.push((check.solver, e));
+                    cell
+                }
+            },
+            status.to_string(),
+        ]);
+    }
+    print_table(&rows);
+    print_errors(&errors);
+
+    if failures > 0 {
+        bail!("{} answer(s) failed or did not match", failures);
+    }
+    Ok(())
+}
+
+fn print_table(rows: &[Vec<String>]) {
+    let mut widths = vec![0; rows.iter().map(Vec::len).max().unwrap_or(0)];
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    for row in rows {
+        let line: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:width$}", cell))
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+    }
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+    match Cli::parse().command {
+        Command::Run {
+            day,
+            part,
+            inputs: Some(dir),
+            ..
+        } => compare(day, part, &dir),
+        Command::Run {
+            day,
+            part,
+            input,
+            explain: Some(filter),
+            ..
+        } => explain(day, part, input, &filter),
+        Command::Run {
+            day,
+            part,
+            input,
+            inputs: None,
+            time,
+            lenient,
+            format,
+            jobs,
+            explain: None,
+        } => {
+            let mode = if lenient { Mode::Lenient } else { Mode::Strict };
+            let jobs = jobs
+                .or_else(|| thread::available_parallelism().ok())
+                .map_or(1, NonZeroUsize::get);
+            run(day, part, input, time, mode, format, jobs)
+        }
+        Command::Fetch { day } => fetch(day),
+        Command::Submit { day, part } => submit(day, part),
+        Command::New { day } => new(day),
+        Command::Diff {
+            day,
+            generated,
+            seed,
+            size,
+        } => diff(day, generated, seed, size),
+        Command::Gen { day, size, seed } => gen(day, size, seed),
+        Command::Verify { dir } => verify(&dir),
+    }
+}
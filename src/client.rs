@@ -0,0 +1,203 @@
+use std::{fs, path::Path};
+
+use color_eyre::{
+    eyre::{bail, eyre, Context},
+    Result,
+};
+
+use crate::{config::Config, runner::Part, submit::Verdict};
+
+#[cfg(test)]
+mod stub;
+
+const YEAR: u32 = 2023;
+const USER_AGENT: &str = "github.com/sadlerap/aoc-2023";
+
+/// A client for the Advent of Code site, authenticated with a session token.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: Option<String>,
+}
+
+/// Where a fetched input came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+impl Client {
+    /// Creates a client. Without a session token, only cached inputs are available.
+    pub fn new(base_url: &str, session: Option<&str>) -> Self {
+        Client {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.map(str::to_string),
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Self {
+        Client::new(config.base_url(), config.session.as_deref())
+    }
+
+    fn session(&self) -> Result<&str> {
+        self.session.as_deref().ok_or_else(|| {
+            eyre!(
+                "No session token: set AOC_SESSION, or `session` in {}",
+                Config::PATH
+            )
+        })
+    }
+
+    /// Downloads the input for `day`.
+    pub fn download_input(&self, day: u32) -> Result<String> {
+        let session = self.session()?;
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+        let response = match self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .call()
+        {
+            Ok(response) => response,
+            Err(ureq::Error::Status(code, _)) => {
+                bail!("Failed to download input for day {}: HTTP {}", day, code)
+            }
+            Err(e) => {
+                return Err(e).wrap_err_with(|| format!("Failed to download input for day {}", day))
+            }
+        };
+        response
+            .into_string()
+            .wrap_err_with(|| format!("Failed to read input for day {}", day))
+    }
+
+    /// Submits `answer` for `part` of `day`, and reports what the site made of it.
+    pub fn submit_answer(&self, day: u32, part: Part, answer: &str) -> Result<Verdict> {
+        let session = self.session()?;
+        let url = format!("{}/{}/day/{}/answer", self.base_url, YEAR, day);
+        let response = match self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+        {
+            Ok(response) => response,
+            Err(ureq::Error::Status(code, _)) => {
+                bail!("Failed to submit answer for day {}: HTTP {}", day, code)
+            }
+            Err(e) => {
+                return Err(e).wrap_err_with(|| format!("Failed to submit answer for day {}", day))
+            }
+        };
+        let page = response
+            .into_string()
+            .wrap_err_with(|| format!("Failed to read response for day {}", day))?;
+        Verdict::parse(&page).ok_or_else(|| eyre!("Unrecognised response to submission: {}", page))
+    }
+
+    /// Downloads the input for `day` to `path`, unless it has already been downloaded there.
+    pub fn fetch_input(&self, day: u32, path: &Path) -> Result<Fetched> {
+        if path.exists() {
+            return Ok(Fetched::Cached);
+        }
+        let input = self.download_input(day)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .wrap_err_with(|| format!("Failed to create {}", dir.display()))?;
+        }
+        fs::write(path, input).wrap_err_with(|| format!("Failed to write {}", path.display()))?;
+        Ok(Fetched::Downloaded)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::submit::Hint;
+    use stub::{Response, StubServer};
+
+    #[test]
+    fn test_fetch_downloads_with_session() -> Result<()> {
+        let server = StubServer::start(vec![Response::ok("Time: 7\nDistance: 9\n")]);
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("day06.txt");
+
+        let client = Client::new(&server.url(), Some("secret"));
+        assert_eq!(client.fetch_input(6, &path)?, Fetched::Downloaded);
+        assert_eq!(fs::read_to_string(&path)?, "Time: 7\nDistance: 9\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2023/day/6/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_never_redownloads() -> Result<()> {
+        let server = StubServer::start(vec![Response::ok("first"), Response::ok("second")]);
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("inputs").join("day01.txt");
+
+        let client = Client::new(&server.url(), Some("secret"));
+        assert_eq!(client.fetch_input(1, &path)?, Fetched::Downloaded);
+        assert_eq!(client.fetch_input(1, &path)?, Fetched::Cached);
+        assert_eq!(fs::read_to_string(&path)?, "first");
+        assert_eq!(server.requests().len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_cached_without_session() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("day04.txt");
+        fs::write(&path, "cached")?;
+
+        let client = Client::new("http://127.0.0.1:1", None);
+        assert_eq!(client.fetch_input(4, &path)?, Fetched::Cached);
+        assert!(client.download_input(4).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_submit_answer() -> Result<()> {
+        let server = StubServer::start(vec![
+            Response::ok("<p>That's not the right answer; your answer is too low.</p>"),
+            Response::ok("<p>That's the right answer!</p>"),
+        ]);
+        let client = Client::new(&server.url(), Some("secret"));
+
+        assert_eq!(
+            client.submit_answer(7, Part::Two, "100")?,
+            Verdict::Incorrect {
+                hint: Some(Hint::TooLow),
+                wait: None
+            }
+        );
+        assert_eq!(client.submit_answer(7, Part::Two, "200")?, Verdict::Correct);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2023/day/7/answer");
+        assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+        assert_eq!(requests[0].body, "level=2&answer=100");
+        assert_eq!(requests[1].body, "level=2&answer=200");
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_error_status() -> Result<()> {
+        let server = StubServer::start(vec![Response::new(400, "Please log in")]);
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("day02.txt");
+
+        let client = Client::new(&server.url(), Some("expired"));
+        let error = client.fetch_input(2, &path).unwrap_err();
+        assert!(error.to_string().contains("HTTP 400"), "{}", error);
+        assert!(!path.exists());
+        Ok(())
+    }
+}
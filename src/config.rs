@@ -0,0 +1,47 @@
+use std::{env, path::Path};
+
+use color_eyre::{eyre::Context, Result};
+use serde::Deserialize;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings for talking to the Advent of Code site.
+///
+/// These are read from `aoc.toml` in the working directory, and can be overridden with the
+/// `AOC_SESSION` and `AOC_BASE_URL` environment variables.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The value of the `session` cookie from a logged-in browser.
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    pub const PATH: &'static str = "aoc.toml";
+
+    pub fn load() -> Result<Self> {
+        let mut config = Self::from_file(Path::new(Self::PATH))?;
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+        Ok(config)
+    }
+
+    /// Reads the config at `path`, or the default config if it doesn't exist.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+        let contents = std::fs::read_to_string(path)
+            .wrap_err_with(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&contents).wrap_err_with(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL)
+    }
+}
@@ -0,0 +1,1116 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt, fs,
+    io::BufRead,
+    ops::Range,
+    path::Path,
+    sync::LazyLock,
+};
+
+use color_eyre::{
+    eyre::{bail, Context, Result},
+    Report,
+};
+
+use crate::{error::Error, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed<'a> = &'a str;
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Parsed<'_>) -> Result<Self::Output> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Parsed<'_>) -> Result<Self::Output> {
+        part2(input)
+    }
+}
+
+fn is_num(c: u8) -> Option<u32> {
+    if c.is_ascii_digit() {
+        Some((c - b'0') as u32)
+    } else {
+        None
+    }
+}
+
+/// Sums the calibration values, finding newlines and digits 16 or 32 bytes at a time with SSE2 or
+/// AVX2 where the CPU has them, and 8 at a time (SIMD within a register) otherwise.
+pub fn part1(input: &str) -> Result<u32> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            // SAFETY: the CPU has AVX2
+            return unsafe { simd::x86::sum_avx2(input) };
+        }
+        // every x86-64 CPU has SSE2, so it's always enabled
+        sum_first_last::<simd::x86::Sse2>(input)
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    sum_first_last::<simd::Swar>(input)
+}
+
+/// Like [`part1`], but always searching 8 bytes at a time, for comparison.
+pub fn part1_swar(input: &str) -> Result<u32> {
+    sum_first_last::<simd::Swar>(input)
+}
+
+#[inline]
+fn sum_first_last<S: simd::Search>(input: &str) -> Result<u32> {
+    use simd::Class::{Digit, Newline};
+
+    let bytes = input.as_bytes();
+    let mut sum = 0u32;
+    let mut start = 0;
+    let mut number = 1;
+    while start < bytes.len() {
+        let end = S::find(&bytes[start..], Newline).map_or(bytes.len(), |i| start + i);
+        let line = &bytes[start..end];
+        let Some(first) = S::find(line, Digit) else {
+            let line = &input[start..end];
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            bail!(Error::invalid(format!("Failed to find first digit in record {}", line))
+                .at_line(number))
+        };
+        // there's at least the first digit, so this always finds one
+        let last = first + S::rfind(&line[first..], Digit).unwrap_or(0);
+        let value = ((line[first] - b'0') * 10 + (line[last] - b'0')) as u32;
+        sum = sum.checked_add(value).ok_or_else(Error::overflow)?;
+        start = end + 1;
+        number += 1;
+    }
+    Ok(sum)
+}
+
+/// Like [`part1`], but walking each line a byte at a time, for comparison.
+pub fn part1_scalar(input: &str) -> Result<u32> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let Some((first_digit, index)) = line
+                .bytes()
+                .enumerate()
+                .find_map(|(index, c)| is_num(c).map(|r| (r, index)))
+            else {
+                bail!(Error::invalid(format!("Failed to find first digit in record {}", line))
+                    .at_line(i + 1))
+            };
+            let last_digit = line
+                .bytes()
+                .skip(index)
+                .rev()
+                .find_map(is_num)
+                .unwrap_or(first_digit);
+            Ok(first_digit * 10 + last_digit)
+        })
+        .try_fold(0u32, |x, y: Result<u32>| {
+            x.checked_add(y?).ok_or_else(|| Error::overflow().into())
+        })
+}
+
+/// Searching bytes 8 at a time, as the lanes of a `u64` (SIMD within a register).
+///
+/// The masks have the top bit of each lane set for the bytes that match and every other bit clear,
+/// so the first match is in the lowest set lane and the last in the highest. Whatever doesn't fill
+/// a whole word, including lines shorter than one, is searched a byte at a time.
+mod swar {
+    const LANES: usize = 8;
+    const LOW: u64 = 0x7f7f_7f7f_7f7f_7f7f;
+    const HIGH: u64 = 0x8080_8080_8080_8080;
+
+    const fn splat(byte: u8) -> u64 {
+        u64::from_ne_bytes([byte; LANES])
+    }
+
+    /// The lanes that are `b'\n'`.
+    pub fn newlines(word: u64) -> u64 {
+        let x = word ^ splat(b'\n');
+        // a lane is zero if adding 0x7f to its low bits doesn't carry into its top bit, and its
+        // top bit was clear to begin with
+        !(((x & LOW) + LOW) | x | LOW)
+    }
+
+    /// The lanes that are ASCII digits.
+    pub fn digits(word: u64) -> u64 {
+        // none of these additions carry out of a lane, since the lanes are at most 0x7f
+        let low = word & LOW;
+        let at_least_0 = low + splat(0x80 - b'0');
+        let above_9 = low + splat(0x80 - b'9' - 1);
+        at_least_0 & !above_9 & !word & HIGH
+    }
+
+    fn word(chunk: &[u8]) -> u64 {
+        u64::from_le_bytes(chunk.try_into().unwrap())
+    }
+
+    /// The index of the first byte in `bytes` that `mask` matches.
+    pub fn find(bytes: &[u8], mask: fn(u64) -> u64) -> Option<usize> {
+        let chunks = bytes.chunks_exact(LANES);
+        let rest = chunks.remainder();
+        for (i, chunk) in chunks.enumerate() {
+            let found = mask(word(chunk));
+            if found != 0 {
+                return Some(i * LANES + found.trailing_zeros() as usize / 8);
+            }
+        }
+        let offset = bytes.len() - rest.len();
+        rest.iter()
+            .position(|&byte| mask(splat(byte)) != 0)
+            .map(|i| offset + i)
+    }
+
+    /// The index of the last byte in `bytes` that `mask` matches.
+    pub fn rfind(bytes: &[u8], mask: fn(u64) -> u64) -> Option<usize> {
+        let chunks = bytes.rchunks_exact(LANES);
+        let rest = chunks.remainder();
+        for (i, chunk) in chunks.enumerate() {
+            let found = mask(word(chunk));
+            if found != 0 {
+                let lane = (63 - found.leading_zeros() as usize) / 8;
+                return Some(bytes.len() - (i + 1) * LANES + lane);
+            }
+        }
+        rest.iter().rposition(|&byte| mask(splat(byte)) != 0)
+    }
+}
+
+/// Searching bytes 16 or 32 at a time, with the SSE2 or AVX2 instructions of x86-64 CPUs.
+///
+/// Whatever doesn't fill a whole vector, including lines shorter than one, is searched with
+/// [`swar`], which is also used on its own on other CPUs.
+mod simd {
+    use super::swar;
+
+    /// The bytes to search for.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Class {
+        Newline,
+        Digit,
+    }
+
+    impl Class {
+        #[inline]
+        fn swar(self) -> fn(u64) -> u64 {
+            match self {
+                Class::Newline => swar::newlines,
+                Class::Digit => swar::digits,
+            }
+        }
+    }
+
+    /// A way of finding the first and last bytes of a [`Class`].
+    pub trait Search {
+        fn find(bytes: &[u8], class: Class) -> Option<usize>;
+        fn rfind(bytes: &[u8], class: Class) -> Option<usize>;
+    }
+
+    pub struct Swar;
+
+    impl Search for Swar {
+        #[inline]
+        fn find(bytes: &[u8], class: Class) -> Option<usize> {
+            swar::find(bytes, class.swar())
+        }
+
+        #[inline]
+        fn rfind(bytes: &[u8], class: Class) -> Option<usize> {
+            swar::rfind(bytes, class.swar())
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    pub(super) mod x86 {
+        use std::arch::x86_64::*;
+
+        use color_eyre::Result;
+
+        use super::{swar, Class, Search};
+
+        /// Defines a [`Search`] `$name` over vectors of `$lanes` bytes, from `$feature`'s versions
+        /// of the intrinsics. It may only be used where `$feature` is enabled.
+        macro_rules! search {
+            (
+                $name:ident, $feature:literal, $lanes:literal, $vector:ty,
+                $load:ident, $set1:ident, $sub:ident, $min:ident, $cmpeq:ident, $movemask:ident
+            ) => {
+                pub struct $name;
+
+                impl $name {
+                    /// A bit for each byte in `chunk` that's in `class`, from the lowest up.
+                    #[inline]
+                    #[target_feature(enable = $feature)]
+                    fn mask(chunk: &[u8], class: Class) -> u32 {
+                        assert_eq!(chunk.len(), $lanes);
+                        // SAFETY: the chunk has a whole vector's worth of bytes, and the load
+                        // doesn't need them to be aligned
+                        let bytes = unsafe { $load(chunk.as_ptr().cast::<$vector>()) };
+                        let found = match class {
+                            Class::Newline => $cmpeq(bytes, $set1(b'\n' as i8)),
+                            Class::Digit => {
+                                // there's no unsigned comparison, but a byte is at most 9 after
+                                // taking away b'0' if it's the smaller of it and 9
+                                let x = $sub(bytes, $set1(b'0' as i8));
+                                $cmpeq($min(x, $set1(9)), x)
+                            }
+                        };
+                        $movemask(found) as u32
+                    }
+                }
+
+                impl Search for $name {
+                    #[inline]
+                    fn find(bytes: &[u8], class: Class) -> Option<usize> {
+                        let chunks = bytes.chunks_exact($lanes);
+                        let rest = chunks.remainder();
+                        for (i, chunk) in chunks.enumerate() {
+                            // SAFETY: the search is only used where the feature is enabled
+                            let found = unsafe { Self::mask(chunk, class) };
+                            if found != 0 {
+                                return Some(i * $lanes + found.trailing_zeros() as usize);
+                            }
+                        }
+                        let offset = bytes.len() - rest.len();
+                        swar::find(rest, class.swar()).map(|i| offset + i)
+                    }
+
+                    #[inline]
+                    fn rfind(bytes: &[u8], class: Class) -> Option<usize> {
+                        let chunks = bytes.rchunks_exact($lanes);
+                        let rest = chunks.remainder();
+                        for (i, chunk) in chunks.enumerate() {
+                            // SAFETY: as for `find`
+                            let found = unsafe { Self::mask(chunk, class) };
+                            if found != 0 {
+                                let lane = 31 - found.leading_zeros() as usize;
+                                return Some(bytes.len() - (i + 1) * $lanes + lane);
+                            }
+                        }
+                        swar::rfind(rest, class.swar())
+                    }
+                }
+            };
+        }
+
+        search!(
+            Sse2, "sse2", 16, __m128i,
+            _mm_loadu_si128, _mm_set1_epi8, _mm_sub_epi8, _mm_min_epu8, _mm_cmpeq_epi8,
+            _mm_movemask_epi8
+        );
+        search!(
+            Avx2, "avx2", 32, __m256i,
+            _mm256_loadu_si256, _mm256_set1_epi8, _mm256_sub_epi8, _mm256_min_epu8,
+            _mm256_cmpeq_epi8, _mm256_movemask_epi8
+        );
+
+        /// [`super::super::part1`] with [`Avx2`], compiled for CPUs with AVX2 so that its
+        /// searches are inlined.
+        #[target_feature(enable = "avx2")]
+        pub fn sum_avx2(input: &str) -> Result<u32> {
+            super::super::sum_first_last::<Avx2>(input)
+        }
+    }
+}
+
+/// The English words for the digits 1 to 9, and the digits themselves, as a [`Dictionary`] takes
+/// them.
+pub const ENGLISH: &[(&str, u32)] = &[
+    ("one", 1),
+    ("1", 1),
+    ("two", 2),
+    ("2", 2),
+    ("three", 3),
+    ("3", 3),
+    ("four", 4),
+    ("4", 4),
+    ("five", 5),
+    ("5", 5),
+    ("six", 6),
+    ("6", 6),
+    ("seven", 7),
+    ("7", 7),
+    ("eight", 8),
+    ("8", 8),
+    ("nine", 9),
+    ("9", 9),
+];
+
+/// The words that spell out digits in calibration lines, and the digits they stand for.
+///
+/// A line's first digit is the word that starts first, and its last digit the word that ends
+/// last, taking the longest word if several start (or end) at the same place. Words may be
+/// prefixes of each other or overlap.
+#[derive(Clone)]
+pub struct Dictionary {
+    words: Vec<(String, u32)>,
+    forward: Matcher,
+    backward: Matcher,
+}
+
+static ENGLISH_DICTIONARY: LazyLock<Dictionary> =
+    LazyLock::new(|| Dictionary::new(ENGLISH.iter().copied()).unwrap());
+static DIGITS_DICTIONARY: LazyLock<Dictionary> = LazyLock::new(|| {
+    let digits = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+    Dictionary::new((0..).zip(digits).map(|(digit, word)| (word, digit))).unwrap()
+});
+
+impl Dictionary {
+    /// A dictionary of `words`, which should include the digits themselves if they count. Each
+    /// word must be non-empty and stand for a single digit.
+    pub fn new<'a>(words: impl IntoIterator<Item = (&'a str, u32)>) -> Result<Self, Error> {
+        let mut entries: Vec<(String, u32)> = Vec::new();
+        for (word, digit) in words {
+            if word.is_empty() {
+                return Err(Error::invalid("A dictionary word can't be empty"));
+            }
+            if digit > 9 {
+                return Err(Error::invalid(format!(
+                    "{} stands for {}, which isn't a digit",
+                    word, digit
+                )));
+            }
+            match entries.iter().find(|(other, _)| other == word) {
+                Some((_, other)) if *other != digit => {
+                    return Err(Error::invalid(format!(
+                        "{} stands for both {} and {}",
+                        word, other, digit
+                    )))
+                }
+                Some(_) => {}
+                None => entries.push((word.to_string(), digit)),
+            }
+        }
+        let bytes = || entries.iter().map(|(word, digit)| (word.as_bytes(), *digit));
+        Ok(Dictionary {
+            forward: Matcher::new(bytes(), false)?,
+            backward: Matcher::new(bytes(), true)?,
+            words: entries,
+        })
+    }
+
+    /// The digits 1 to 9 and their English words.
+    pub fn english() -> &'static Dictionary {
+        &ENGLISH_DICTIONARY
+    }
+
+    /// Just the digits 0 to 9, as in part 1.
+    pub fn digits() -> &'static Dictionary {
+        &DIGITS_DICTIONARY
+    }
+
+    /// Reads a dictionary from a TOML file of words and their digits, like `zero = 0`. Digits are
+    /// words too, so `"0" = 0` makes 0 count.
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .wrap_err_with(|| format!("Failed to read {}", path.display()))?;
+        let words: BTreeMap<String, u32> = toml::from_str(&contents)
+            .wrap_err_with(|| format!("Failed to parse {}", path.display()))?;
+        Ok(Dictionary::new(
+            words.iter().map(|(word, digit)| (word.as_str(), *digit)),
+        )?)
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words.iter().map(|(word, digit)| (word.as_str(), *digit))
+    }
+}
+
+impl Default for Dictionary {
+    fn default() -> Self {
+        Dictionary::english().clone()
+    }
+}
+
+impl fmt::Debug for Dictionary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dictionary")
+            .field("words", &self.words)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The longest of `words` at the start of `input`, as its length and digit.
+fn starts_with_digit(input: &str, words: &[(&str, u32)]) -> Option<(usize, u32)> {
+    words
+        .iter()
+        .filter(|(word, _)| input.starts_with(word))
+        .map(|(word, digit)| (word.len(), *digit))
+        .max_by_key(|(len, _)| *len)
+}
+
+/// Finds the first and last digits by trying every one of `words` at each offset. [`part2`] does
+/// the same with a [`Matcher`] instead.
+fn parse_first_last_scan(line: &str, words: &[(&str, u32)]) -> Result<u32, Error> {
+    let matches = || {
+        (0..line.len()).filter_map(|i| starts_with_digit(&line[i..], words).map(|x| (i, x)))
+    };
+    let Some((_, (_, first))) = matches().next() else {
+        return Err(Error::invalid(format!(
+            "Failed to find first digit in record {}",
+            line
+        )));
+    };
+    // the first word to end last is the longest
+    let (_, (_, last)) = matches()
+        .reduce(|x, y| if y.0 + y.1 .0 > x.0 + x.1 .0 { y } else { x })
+        .unwrap();
+    Ok(first * 10 + last)
+}
+
+/// A DFA that finds words from a dictionary in a stream of bytes, built like an Aho-Corasick
+/// automaton with every transition worked out ahead of time.
+///
+/// It finds the leftmost-longest match, so a matcher of the words finds a line's first digit, and
+/// a matcher of the reversed words, run over the reversed line, finds its last.
+#[derive(Clone)]
+struct Matcher {
+    /// The next state for each state and byte. State 0 is the start.
+    next: Vec<[u16; 256]>,
+    /// How many bytes each state has matched of the words it's part way through.
+    depth: Vec<usize>,
+    /// The length and digit of the longest word that ends on reaching each state, if any.
+    output: Vec<Option<(usize, u32)>>,
+}
+
+impl Matcher {
+    /// Fails if the words need more states than fit in a `u16`.
+    fn new<'a>(
+        words: impl IntoIterator<Item = (&'a [u8], u32)>,
+        reversed: bool,
+    ) -> Result<Self, Error> {
+        // build a trie of the words
+        let mut next = vec![[0u16; 256]];
+        let mut depth = vec![0];
+        let mut output = vec![None];
+        for (word, digit) in words {
+            let mut state = 0;
+            let bytes: Vec<u8> = if reversed {
+                word.iter().rev().copied().collect()
+            } else {
+                word.to_vec()
+            };
+            for byte in bytes {
+                if next[state][byte as usize] == 0 {
+                    next.push([0; 256]);
+                    depth.push(depth[state] + 1);
+                    output.push(None);
+                    next[state][byte as usize] =
+                        u16::try_from(next.len() - 1).map_err(|_| {
+                            Error::invalid("The dictionary is too big to match")
+                        })?;
+                }
+                state = next[state][byte as usize] as usize;
+            }
+            output[state] = Some((word.len(), digit));
+        }
+
+        // then fill in the missing transitions breadth first, each from the state for the longest
+        // suffix that's also in the trie
+        let mut fail = vec![0; next.len()];
+        let mut queue: VecDeque<usize> = next[0]
+            .iter()
+            .filter(|&&child| child != 0)
+            .map(|&child| child as usize)
+            .collect();
+        while let Some(state) = queue.pop_front() {
+            if output[state].is_none() {
+                output[state] = output[fail[state]];
+            }
+            let fallbacks = next[fail[state]];
+            for (child, fallback) in next[state].iter_mut().zip(fallbacks) {
+                if *child == 0 {
+                    *child = fallback;
+                } else {
+                    fail[*child as usize] = fallback as usize;
+                    queue.push_back(*child as usize);
+                }
+            }
+        }
+
+        Ok(Matcher {
+            next,
+            depth,
+            output,
+        })
+    }
+
+    /// The start, length and digit of the word that starts first in `bytes`, or the longest of
+    /// those that do.
+    fn find(&self, bytes: impl IntoIterator<Item = u8>) -> Option<(usize, usize, u32)> {
+        let mut state = 0;
+        // the start, length and digit of the best match so far
+        let mut best: Option<(usize, usize, u32)> = None;
+        for (end, byte) in (1..).zip(bytes) {
+            state = self.next[state][byte as usize] as usize;
+            if let Some((len, digit)) = self.output[state] {
+                // a match that starts where the best one does, but ends later, is longer
+                if best.is_none_or(|(start, _, _)| end - len <= start) {
+                    best = Some((end - len, len, digit));
+                }
+            }
+            // stop once no word we're part way through could start early enough to do better
+            if best.is_some_and(|(start, _, _)| end - self.depth[state] > start) {
+                break;
+            }
+        }
+        best
+    }
+}
+
+/// Where a digit was found in a line, and what it was.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// The bytes of the line that spell out the digit.
+    pub span: Range<usize>,
+    pub digit: u32,
+    pub kind: MatchKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchKind {
+    /// The digit itself.
+    Digit,
+    /// Any other word for it.
+    Word,
+}
+
+impl Match {
+    fn new(line: &str, span: Range<usize>, digit: u32) -> Self {
+        let kind = match line.as_bytes()[span.clone()] {
+            [byte] if byte.is_ascii_digit() => MatchKind::Digit,
+            _ => MatchKind::Word,
+        };
+        Match { span, digit, kind }
+    }
+}
+
+/// The first and last digits in a line, as matched by `dictionary`.
+fn first_last(line: &str, dictionary: &Dictionary) -> Result<(Match, Match), Error> {
+    let Some((start, len, digit)) = dictionary.forward.find(line.bytes()) else {
+        return Err(Error::invalid(format!(
+            "Failed to find first digit in record {}",
+            line
+        )));
+    };
+    let first = Match::new(line, start..start + len, digit);
+    let last = match dictionary.backward.find(line.bytes().rev()) {
+        Some((start, len, digit)) => {
+            let end = line.len() - start;
+            Match::new(line, end - len..end, digit)
+        }
+        None => first.clone(),
+    };
+    Ok((first, last))
+}
+
+fn parse_first_last(line: &str, dictionary: &Dictionary) -> Result<u32, Error> {
+    let (first, last) = first_last(line, dictionary)?;
+    Ok(first.digit * 10 + last.digit)
+}
+
+/// How a line was decoded into its calibration value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Calibration<'a> {
+    /// 1-based line number in the input.
+    pub number: usize,
+    pub line: &'a str,
+    pub first: Match,
+    pub last: Match,
+    pub value: u32,
+}
+
+/// Shows the line with its first and last digits underlined, and what each was.
+impl fmt::Display for Calibration<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Line {}: {}", self.number, self.value)?;
+        writeln!(f, "  {}", self.line)?;
+        let underline: String = self
+            .line
+            .char_indices()
+            .map(|(i, _)| {
+                if self.first.span.contains(&i) || self.last.span.contains(&i) {
+                    '^'
+                } else {
+                    ' '
+                }
+            })
+            .collect();
+        writeln!(f, "  {}", underline.trim_end())?;
+        for (name, found) in [("first", &self.first), ("last", &self.last)] {
+            let kind = match found.kind {
+                MatchKind::Digit => "digit",
+                MatchKind::Word => "word",
+            };
+            writeln!(
+                f,
+                "  {}: {:?} at {}..{}, the {} {}",
+                name,
+                &self.line[found.span.clone()],
+                found.span.start,
+                found.span.end,
+                kind,
+                found.digit
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Decodes each line of `input` with `dictionary`, showing where its digits were found.
+pub fn calibrations<'a>(input: &'a str, dictionary: &'a Dictionary) -> Calibrations<'a> {
+    Calibrations {
+        lines: input.lines().enumerate(),
+        dictionary,
+    }
+}
+
+/// An iterator over how each line of an input was decoded, or why it couldn't be. Made by
+/// [`calibrations`].
+pub struct Calibrations<'a> {
+    lines: std::iter::Enumerate<std::str::Lines<'a>>,
+    dictionary: &'a Dictionary,
+}
+
+impl<'a> Iterator for Calibrations<'a> {
+    type Item = Result<Calibration<'a>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, line) = self.lines.next()?;
+        Some(
+            first_last(line, self.dictionary)
+                .map(|(first, last)| Calibration {
+                    number: i + 1,
+                    line,
+                    value: first.digit * 10 + last.digit,
+                    first,
+                    last,
+                })
+                .map_err(|e| e.at_line(i + 1)),
+        )
+    }
+}
+
+pub fn part2(input: &str) -> Result<u32> {
+    part2_with(input, Dictionary::english())
+}
+
+/// Like [`part2`], but with the digits spelled out in `dictionary`'s words.
+pub fn part2_with(input: &str, dictionary: &Dictionary) -> Result<u32> {
+    sum_lines(input, |line| parse_first_last(line, dictionary))
+}
+
+/// Like [`part2`], but scanning the [`ENGLISH`] table at every offset, for comparison.
+pub fn part2_scan(input: &str) -> Result<u32> {
+    sum_lines(input, |line| parse_first_last_scan(line, ENGLISH))
+}
+
+/// Like [`part1`], but reading the input from `reader` a piece at a time, so that it never has
+/// to be in memory all at once. The answer is a `u64`, since inputs that big can add up to more
+/// than a `u32` holds.
+pub fn part1_read(reader: impl BufRead) -> Result<u64> {
+    sum_read(reader, Dictionary::digits())
+}
+
+/// Like [`part2`], but reading the input as [`part1_read`] does.
+pub fn part2_read(reader: impl BufRead) -> Result<u64> {
+    sum_read(reader, Dictionary::english())
+}
+
+/// Like [`part2_with`], but reading the input as [`part1_read`] does.
+pub fn part2_read_with(reader: impl BufRead, dictionary: &Dictionary) -> Result<u64> {
+    sum_read(reader, dictionary)
+}
+
+/// Feeds everything `reader` has to a [`Decoder`], without keeping more than one of its buffers.
+fn sum_read(mut reader: impl BufRead, dictionary: &Dictionary) -> Result<u64> {
+    let mut decoder = Decoder::new(&dictionary.forward);
+    loop {
+        let buffer = reader.fill_buf().wrap_err("Failed to read the input")?;
+        if buffer.is_empty() {
+            return Ok(decoder.finish()?);
+        }
+        let len = buffer.len();
+        decoder.feed(buffer)?;
+        reader.consume(len);
+    }
+}
+
+/// Decodes calibration lines as their bytes arrive, in however many pieces.
+///
+/// Only the forward [`Matcher`] is needed: the line's first digit is the leftmost-longest match
+/// as for [`Matcher::find`], and its last the longest word to end last, which is whatever matched
+/// most recently. As the matcher's state carries over from one piece to the next, so do words
+/// split between them.
+struct Decoder<'a> {
+    matcher: &'a Matcher,
+    state: usize,
+    /// How many bytes of the current line have been seen.
+    end: usize,
+    /// The start, length and digit of the current line's first digit so far.
+    first: Option<(usize, usize, u32)>,
+    last: u32,
+    /// The start of the current line, for errors. Lines can be any length, so it's cut short.
+    prefix: Vec<u8>,
+    line: usize,
+    sum: u64,
+}
+
+/// How much of a line a [`Decoder`] keeps to show in an error.
+const PREFIX_LEN: usize = 80;
+
+impl<'a> Decoder<'a> {
+    fn new(matcher: &'a Matcher) -> Self {
+        Decoder {
+            matcher,
+            state: 0,
+            end: 0,
+            first: None,
+            last: 0,
+            prefix: Vec::with_capacity(PREFIX_LEN),
+            line: 1,
+            sum: 0,
+        }
+    }
+
+    fn feed(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        for &byte in bytes {
+            if byte == b'\n' {
+                self.end_line()?;
+                continue;
+            }
+            self.state = self.matcher.next[self.state][byte as usize] as usize;
+            self.end += 1;
+            if self.end <= PREFIX_LEN {
+                self.prefix.push(byte);
+            }
+            if let Some((len, digit)) = self.matcher.output[self.state] {
+                if self
+                    .first
+                    .is_none_or(|(start, _, _)| self.end - len <= start)
+                {
+                    self.first = Some((self.end - len, len, digit));
+                }
+                self.last = digit;
+            }
+        }
+        Ok(())
+    }
+
+    fn end_line(&mut self) -> Result<(), Error> {
+        let Some((_, _, first)) = self.first else {
+            let prefix = String::from_utf8_lossy(&self.prefix);
+            let mut line = prefix.strip_suffix('\r').unwrap_or(&prefix).to_string();
+            if self.end > PREFIX_LEN {
+                line.push_str("...");
+            }
+            return Err(Error::invalid(format!(
+                "Failed to find first digit in record {}",
+                line
+            ))
+            .at_line(self.line));
+        };
+        self.sum = self
+            .sum
+            .checked_add((first * 10 + self.last) as u64)
+            .ok_or_else(|| Error::overflow().at_line(self.line))?;
+        self.state = 0;
+        self.end = 0;
+        self.first = None;
+        self.prefix.clear();
+        self.line += 1;
+        Ok(())
+    }
+
+    /// The sum of every line, including a last one without a newline.
+    fn finish(mut self) -> Result<u64, Error> {
+        if self.end > 0 {
+            self.end_line()?;
+        }
+        Ok(self.sum)
+    }
+}
+
+fn sum_lines(input: &str, parse: impl Fn(&str) -> Result<u32, Error>) -> Result<u32> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|e| e.at_line(i + 1)))
+        .try_fold(0u32, |x, y| x.checked_add(y?).ok_or_else(Error::overflow))
+        .map_err(Report::new)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generate::Rng;
+
+    #[test]
+    fn test_line_endings() {
+        for input in ["1abc2\npqr3stu8vwx\n", "1abc2\r\npqr3stu8vwx\r\n", "1abc2\npqr3stu8vwx"] {
+            assert_eq!(part1(input).unwrap(), 50);
+        }
+        for input in ["two1nine\nabcone2threexyz\n", "two1nine\r\nabcone2threexyz"] {
+            assert_eq!(part2(input).unwrap(), 42);
+        }
+    }
+
+    #[test]
+    fn test_swar_masks() {
+        for byte in 0..=u8::MAX {
+            let mut lanes = [b'x'; 8];
+            lanes[3] = byte;
+            let word = u64::from_le_bytes(lanes);
+            let expected = |matches: bool| if matches { 0x80 << 24 } else { 0 };
+            assert_eq!(swar::digits(word), expected(byte.is_ascii_digit()), "{byte}");
+            assert_eq!(swar::newlines(word), expected(byte == b'\n'), "{byte}");
+        }
+    }
+
+    #[test]
+    fn test_part1_agrees_with_scalar() {
+        // lines either side of a word and a vector long, with digits at each end of a word or a
+        // vector and in the remainder
+        let mut input = String::new();
+        for len in 1..=70 {
+            for at in 0..len {
+                let mut line = vec![b'a'; len];
+                line[at] = b'1' + (at % 9) as u8;
+                line[len - 1 - at / 2] = b'9' - (len % 9) as u8;
+                input.push_str(std::str::from_utf8(&line).unwrap());
+                input.push('\n');
+            }
+        }
+        assert_eq!(part1(&input).unwrap(), part1_scalar(&input).unwrap());
+        assert_eq!(part1_swar(&input).unwrap(), part1_scalar(&input).unwrap());
+        for seed in 0..20 {
+            let input = crate::generate::generate(1, seed, 100).unwrap();
+            assert_eq!(part1(&input).unwrap(), part1_scalar(&input).unwrap());
+            assert_eq!(part1_swar(&input).unwrap(), part1_scalar(&input).unwrap());
+        }
+        for input in ["abcdefghijklmnop\n", "1\n\n2\n", &"a".repeat(40)] {
+            let scalar = part1_scalar(input).unwrap_err().to_string();
+            assert_eq!(part1(input).unwrap_err().to_string(), scalar);
+            assert_eq!(part1_swar(input).unwrap_err().to_string(), scalar);
+        }
+    }
+
+    #[test]
+    fn test_searches_agree() {
+        use simd::{Class, Search};
+
+        fn check<S: Search>() {
+            for byte in 0..=u8::MAX {
+                for len in [1, 15, 16, 17, 31, 32, 33, 64, 70] {
+                    for at in [0, len / 3, len - 1] {
+                        let mut bytes = vec![b'x'; len];
+                        bytes[at] = byte;
+                        for (class, matches) in [
+                            (Class::Newline, byte == b'\n'),
+                            (Class::Digit, byte.is_ascii_digit()),
+                        ] {
+                            let expected = matches.then_some(at);
+                            assert_eq!(S::find(&bytes, class), expected, "{byte} {len}");
+                            assert_eq!(S::rfind(&bytes, class), expected, "{byte} {len}");
+                        }
+                    }
+                }
+            }
+        }
+
+        check::<simd::Swar>();
+        #[cfg(target_arch = "x86_64")]
+        {
+            check::<simd::x86::Sse2>();
+            if is_x86_feature_detected!("avx2") {
+                check::<simd::x86::Avx2>();
+            }
+        }
+    }
+
+    #[test]
+    fn test_overlapping_words() {
+        assert_eq!(parse_first_last("eightwo", Dictionary::english()).unwrap(), 82);
+        assert_eq!(parse_first_last("twone", Dictionary::english()).unwrap(), 21);
+        assert_eq!(parse_first_last("xoneightx", Dictionary::english()).unwrap(), 18);
+        assert_eq!(parse_first_last("sevenine", Dictionary::english()).unwrap(), 79);
+        assert_eq!(parse_first_last("5", Dictionary::english()).unwrap(), 55);
+        // a partial word has to fall back to a shorter one
+        assert_eq!(parse_first_last("ththree4fivfive", Dictionary::english()).unwrap(), 35);
+    }
+
+    #[test]
+    fn test_matcher_agrees_with_scan() {
+        for seed in 0..20 {
+            let input = crate::generate::generate(1, seed, 100).unwrap();
+            assert_eq!(part2(&input).unwrap(), part2_scan(&input).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_calibrations() {
+        let input = "two1nine\nxtwone3four\n7pqrstsixteen\nnope\n";
+        let lines: Vec<_> = calibrations(input, Dictionary::english()).collect();
+        let first = lines[0].as_ref().unwrap();
+        assert_eq!(
+            (&first.first, &first.last),
+            (
+                &Match {
+                    span: 0..3,
+                    digit: 2,
+                    kind: MatchKind::Word
+                },
+                &Match {
+                    span: 4..8,
+                    digit: 9,
+                    kind: MatchKind::Word
+                }
+            )
+        );
+        assert_eq!(
+            lines[1].as_ref().unwrap().to_string(),
+            "Line 2: 24\n  \
+             xtwone3four\n   \
+             ^^^   ^^^^\n  \
+             first: \"two\" at 1..4, the word 2\n  \
+             last: \"four\" at 7..11, the word 4\n"
+        );
+        let third = lines[2].as_ref().unwrap();
+        assert_eq!((third.first.kind, third.value), (MatchKind::Digit, 76));
+        assert_eq!(
+            lines[3].as_ref().unwrap_err().to_string(),
+            "Line 4: Failed to find first digit in record nope"
+        );
+
+        // with just the digits, a line's only digit is both its first and last
+        let only: Vec<_> = calibrations("a0b\nab7c\n", Dictionary::digits())
+            .map(|calibration| calibration.unwrap().value)
+            .collect();
+        assert_eq!(only, [0, 77]);
+    }
+
+    #[test]
+    fn test_custom_dictionaries() -> Result<()> {
+        let with_zero = Dictionary::new(
+            ENGLISH
+                .iter()
+                .copied()
+                .chain([("zero", 0), ("0", 0)]),
+        )?;
+        assert_eq!(part2_with("zero4\nxone0", &with_zero)?, 4 + 10);
+        assert_eq!(part2("zero4\nxone0")?, 44 + 11);
+
+        // words that are prefixes of each other, or inside each other
+        let dictionary = Dictionary::new([("sept", 7), ("septante", 7), ("ante", 4), ("x", 1)])?;
+        assert_eq!(parse_first_last("septante", &dictionary)?, 77);
+        assert_eq!(parse_first_last("xseptantex", &dictionary)?, 11);
+        assert_eq!(parse_first_last("anteseptan", &dictionary)?, 47);
+
+        for (words, error) in [
+            (vec![("ten", 10)], "ten stands for 10, which isn't a digit"),
+            (vec![("", 1)], "A dictionary word can't be empty"),
+            (vec![("un", 1), ("un", 2)], "un stands for both 1 and 2"),
+        ] {
+            assert_eq!(Dictionary::new(words).unwrap_err().to_string(), error);
+        }
+        let long = "x".repeat(usize::from(u16::MAX) + 1);
+        assert_eq!(
+            Dictionary::new([(long.as_str(), 1)])
+                .unwrap_err()
+                .to_string(),
+            "The dictionary is too big to match"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_dictionary() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("deutsch.toml");
+        fs::write(&path, "eins = 1\nzwei = 2\ndrei = 3\n\"3\" = 3\n")?;
+        let dictionary = Dictionary::load(&path)?;
+        assert_eq!(
+            dictionary.words().collect::<Vec<_>>(),
+            [("3", 3), ("drei", 3), ("eins", 1), ("zwei", 2)]
+        );
+        assert_eq!(part2_with("einsdreizwei\n3x", &dictionary)?, 12 + 33);
+        Ok(())
+    }
+
+    #[test]
+    fn test_matcher_agrees_with_scan_on_random_dictionaries() {
+        // short words over a small alphabet, so that they overlap and contain each other a lot
+        let mut rng = Rng::new(0);
+        for _ in 0..200 {
+            let mut words: Vec<(String, u32)> = Vec::new();
+            for _ in 0..rng.between(1..=6) {
+                let len = rng.between(1..=4) as usize;
+                let word: String = (0..len).map(|_| *rng.choose(&['a', 'b', 'c'])).collect();
+                // a word can only stand for one digit
+                if !words.iter().any(|(other, _)| *other == word) {
+                    words.push((word, rng.below(10) as u32));
+                }
+            }
+            let words: Vec<(&str, u32)> = words
+                .iter()
+                .map(|(word, digit)| (word.as_str(), *digit))
+                .collect();
+            let dictionary = Dictionary::new(words.iter().copied()).unwrap();
+            for _ in 0..20 {
+                let len = rng.between(1..=12) as usize;
+                let line: String = (0..len).map(|_| *rng.choose(&['a', 'b', 'c', 'd'])).collect();
+                assert_eq!(
+                    parse_first_last(&line, &dictionary).ok(),
+                    parse_first_last_scan(&line, &words).ok(),
+                    "{} with {:?}",
+                    line,
+                    words
+                );
+                assert_eq!(
+                    part2_read_with(line.as_bytes(), &dictionary).ok(),
+                    parse_first_last(&line, &dictionary).ok().map(u64::from),
+                    "{} with {:?}",
+                    line,
+                    words
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_in_pieces() -> Result<()> {
+        let mut inputs = vec![
+            "1abc2\r\npqr3stu8vwx\r\n".to_string(),
+            "xtwone3four\nseven9ine".to_string(),
+        ];
+        inputs.extend((0..5).map(|seed| crate::generate::generate(1, seed, 50).unwrap()));
+        for input in &inputs {
+            // down to a byte at a time, so that every word is split between reads somewhere
+            for capacity in [1, 2, 3, 7, 64] {
+                let reader = || std::io::BufReader::with_capacity(capacity, input.as_bytes());
+                assert_eq!(part1_read(reader())?, part1(input)? as u64);
+                assert_eq!(part2_read(reader())?, part2(input)? as u64);
+            }
+        }
+
+        // errors read as they do for the whole input, with long lines cut short
+        let long = "x".repeat(100);
+        for input in ["one\nnope\r\ntwo", "one\n\ntwo", &format!("one\n{}\ntwo", long)] {
+            let reader = std::io::BufReader::with_capacity(2, input.as_bytes());
+            let e = part2_read(reader).unwrap_err().to_string();
+            let expected = part2(input).unwrap_err().to_string();
+            if input.len() > PREFIX_LEN {
+                assert_eq!(e, format!("{}...", &expected[..expected.len() - 20]));
+            } else {
+                assert_eq!(e, expected);
+            }
+        }
+        Ok(())
+    }
+}
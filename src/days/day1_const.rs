@@ -0,0 +1,241 @@
+use color_eyre::Result;
+
+use super::day1::ENGLISH;
+use crate::{error::Error, Solution};
+
+/// Day 1, solved by `const fn`s so that the answers can be computed at compile time.
+pub struct Day1Const;
+
+impl Solution for Day1Const {
+    type Parsed<'a> = &'a [u8];
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input.as_bytes())
+    }
+
+    fn part1(input: &Self::Parsed<'_>) -> Result<Self::Output> {
+        Ok(part1(input).map_err(Error::from)?)
+    }
+
+    fn part2(input: &Self::Parsed<'_>) -> Result<Self::Output> {
+        Ok(part2(input).map_err(Error::from)?)
+    }
+}
+
+/// Why a line couldn't be decoded. It's `Copy` so that it can be handled at compile time, and
+/// converts into an [`Error`] at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineError {
+    NoDigit { line: usize },
+    Overflow { line: usize },
+}
+
+impl From<LineError> for Error {
+    fn from(error: LineError) -> Self {
+        match error {
+            LineError::NoDigit { line } => {
+                Error::invalid("Failed to find a digit in the record").at_line(line)
+            }
+            LineError::Overflow { line } => Error::overflow().at_line(line),
+        }
+    }
+}
+
+/// The answers to both parts for the input named by `AOC_DAY1_CONST_INPUT` at build time (see
+/// `build.rs`), computed by the compiler. `None` if no input was named, and an error rather than a
+/// failed build if the input is invalid.
+#[cfg(day1_const_input)]
+// trying each word at every byte of a whole input is more steps than the lint expects
+#[allow(long_running_const_eval)]
+pub const BUILD_TIME_ANSWERS: Option<Result<(u32, u32), LineError>> = {
+    let input = include_bytes!(env!("AOC_DAY1_CONST_INPUT"));
+    Some(match (part1(input), part2(input)) {
+        (Ok(part1), Ok(part2)) => Ok((part1, part2)),
+        (Err(e), _) | (_, Err(e)) => Err(e),
+    })
+};
+#[cfg(not(day1_const_input))]
+pub const BUILD_TIME_ANSWERS: Option<Result<(u32, u32), LineError>> = None;
+
+pub const fn part1(mut input: &[u8]) -> Result<u32, LineError> {
+    let mut first = None;
+    let mut last = 0;
+    let mut acc: u32 = 0;
+    let mut line = 1;
+    let mut in_line = false;
+    while let Some((next_char, remaining)) = input.split_first() {
+        input = remaining;
+        match next_char {
+            b'\n' => {
+                acc = match add_line(acc, first, last, line) {
+                    Ok(acc) => acc,
+                    Err(e) => return Err(e),
+                };
+                first = None;
+                last = 0;
+                line += 1;
+                in_line = false;
+                continue;
+            }
+            b'0'..=b'9' => {
+                let c = (*next_char - b'0') as u32;
+                if first.is_none() {
+                    first = Some(c);
+                }
+                last = c;
+            }
+            _ => {}
+        }
+        in_line = true;
+    }
+
+    // the last line may not end in a newline
+    if in_line {
+        return add_line(acc, first, last, line);
+    }
+    Ok(acc)
+}
+
+pub const fn part2(input: &[u8]) -> Result<u32, LineError> {
+    part2_with(input, ENGLISH)
+}
+
+/// Like [`part2`], but with the digits spelled out in `words`, which work as they do in a
+/// [`Dictionary`](super::day1::Dictionary).
+pub const fn part2_with(mut input: &[u8], words: &[(&str, u32)]) -> Result<u32, LineError> {
+    // most bytes can't start a word, and the compiler is slow to find that out word by word
+    let mut starts = [false; 256];
+    let mut i = 0;
+    while i < words.len() {
+        if let Some(byte) = words[i].0.as_bytes().first() {
+            starts[*byte as usize] = true;
+        }
+        i += 1;
+    }
+
+    let mut first = None;
+    let mut last = 0;
+    // where the word for `last` ends, so that a word ending later can replace it
+    let mut last_end = 0;
+    let mut position = 0;
+    let mut acc: u32 = 0;
+    let mut line = 1;
+    let mut in_line = false;
+    while let Some((next_char, remaining)) = input.split_first() {
+        if *next_char == b'\n' {
+            acc = match add_line(acc, first, last, line) {
+                Ok(acc) => acc,
+                Err(e) => return Err(e),
+            };
+            first = None;
+            last = 0;
+            last_end = position;
+            line += 1;
+            in_line = false;
+        } else {
+            if !starts[*next_char as usize] {
+                // no word here
+            } else if let Some((len, x)) = longest_at_head(input, words) {
+                if first.is_none() {
+                    first = Some(x);
+                }
+                if position + len > last_end {
+                    last = x;
+                    last_end = position + len;
+                }
+            }
+            in_line = true;
+        }
+        input = remaining;
+        position += 1;
+    }
+
+    // the last line may not end in a newline
+    if in_line {
+        return add_line(acc, first, last, line);
+    }
+    Ok(acc)
+}
+
+/// Adds the calibration value of a finished line to `acc`.
+const fn add_line(acc: u32, first: Option<u32>, last: u32, line: usize) -> Result<u32, LineError> {
+    let Some(first) = first else {
+        return Err(LineError::NoDigit { line });
+    };
+    match acc.checked_add(10 * first + last) {
+        Some(acc) => Ok(acc),
+        None => Err(LineError::Overflow { line }),
+    }
+}
+
+/// The length and digit of the longest of `words` at the start of `input`.
+const fn longest_at_head(input: &[u8], words: &[(&str, u32)]) -> Option<(usize, u32)> {
+    let mut longest = None;
+    let mut i = 0;
+    while i < words.len() {
+        let (word, digit) = words[i];
+        let word = word.as_bytes();
+        if starts_with(input, word) {
+            match longest {
+                Some((len, _)) if len >= word.len() => {}
+                _ => longest = Some((word.len(), digit)),
+            }
+        }
+        i += 1;
+    }
+    longest
+}
+
+const fn starts_with(input: &[u8], word: &[u8]) -> bool {
+    if input.len() < word.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < word.len() {
+        if input[i] != word[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_line_endings() {
+        for input in ["1abc2\npqr3stu8vwx\n", "1abc2\r\npqr3stu8vwx\r\n", "1abc2\npqr3stu8vwx"] {
+            assert_eq!(part1(input.as_bytes()), Ok(50));
+        }
+        for input in ["two1nine\nabcone2threexyz\n", "two1nine\r\nabcone2threexyz"] {
+            assert_eq!(part2(input.as_bytes()), Ok(42));
+        }
+        assert_eq!(part1(b"1abc2\nxyz"), Err(LineError::NoDigit { line: 2 }));
+    }
+
+    #[test]
+    fn test_zero_digits() {
+        // found by `aoc diff 1`: a leading 0 used to look like no digit at all
+        assert_eq!(part1(b"0a5\n"), Ok(5));
+        assert_eq!(part2(b"vp460n\n"), Ok(46));
+    }
+
+    #[test]
+    fn test_custom_words() {
+        const WORDS: &[(&str, u32)] = &[("sept", 7), ("septante", 7), ("ante", 4), ("x", 1)];
+        const ANSWER: Result<u32, LineError> = part2_with(b"septante\nxseptantex\nanteseptan", WORDS);
+        assert_eq!(ANSWER, Ok(77 + 11 + 47));
+        assert_eq!(part2_with(b"zerox\n", &[("zero", 0), ("rox", 1)]), Ok(1));
+    }
+
+    #[cfg(day1_const_input)]
+    #[test]
+    fn test_build_time_answers() {
+        let input = std::fs::read(env!("AOC_DAY1_CONST_INPUT")).unwrap();
+        let answers = part1(&input).and_then(|part1| Ok((part1, part2(&input)?)));
+        assert_eq!(BUILD_TIME_ANSWERS, Some(answers));
+    }
+}
@@ -0,0 +1,320 @@
+use std::fmt;
+
+use color_eyre::Result;
+use winnow::{
+    ascii::{dec_uint, space1},
+    combinator::{alt, cut_err, separated},
+    error::StrContext,
+    prelude::*,
+};
+
+use crate::{diagnostic, error::Error, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed<'a> = Vec<Game>;
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        diagnostic::parse_lines(Game::parse, input)
+    }
+
+    fn part1(games: &Self::Parsed<'_>) -> Result<Self::Output> {
+        Ok(part1(games, 12, 13, 14)?)
+    }
+
+    fn part2(games: &Self::Parsed<'_>) -> Result<Self::Output> {
+        Ok(part2(games)?)
+    }
+}
+
+#[derive(Default, PartialEq, Eq, Debug)]
+pub struct Round {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl Round {
+    pub fn parse(input: &mut &str) -> PResult<Self> {
+        let value = (
+            dec_uint,
+            space1,
+            cut_err(
+                alt((
+                    "red".map(|_| Color::Red),
+                    "green".map(|_| Color::Green),
+                    "blue".map(|_| Color::Blue),
+                ))
+                .context(StrContext::Label("colour"))
+                .context(StrContext::Expected("red".into()))
+                .context(StrContext::Expected("green".into()))
+                .context(StrContext::Expected("blue".into())),
+            ),
+        )
+            .map(|(num, _, color): (u32, _, _)| (num, color));
+
+        // a colour given twice would overwrite the first count, and a round with no cubes would
+        // render as nothing, so both are rejected instead
+        cut_err(
+            separated(1.., value, (",", space1)).try_map(|values: Vec<(u32, Color)>| {
+                let mut round = Round::default();
+                let mut seen = Vec::new();
+                for (num, color) in values {
+                    if seen.contains(&color) {
+                        return Err(Error::invalid(format!(
+                            "{} is shown more than once in the round",
+                            color
+                        )));
+                    }
+                    seen.push(color);
+                    match color {
+                        Color::Red => round.red = num,
+                        Color::Green => round.green = num,
+                        Color::Blue => round.blue = num,
+                    }
+                }
+                if round == Round::default() {
+                    return Err(Error::invalid("The round doesn't show any cubes"));
+                }
+                Ok(round)
+            }),
+        )
+        .parse_next(input)
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Red => write!(f, "red"),
+            Color::Green => write!(f, "green"),
+            Color::Blue => write!(f, "blue"),
+        }
+    }
+}
+
+/// Renders the round as it appears in the input, leaving out colours with no cubes.
+impl fmt::Display for Round {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cubes = [
+            (self.red, Color::Red),
+            (self.green, Color::Green),
+            (self.blue, Color::Blue),
+        ];
+        let cubes: Vec<String> = cubes
+            .iter()
+            .filter(|(num, _)| *num > 0)
+            .map(|(num, color)| format!("{} {}", num, color))
+            .collect();
+        write!(f, "{}", cubes.join(", "))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Round>,
+}
+
+impl Game {
+    pub fn parse(s: &mut &str) -> PResult<Self> {
+        (
+            "Game".context(StrContext::Expected("Game".into())),
+            space1,
+            dec_uint.context(StrContext::Label("game id")),
+            ":".context(StrContext::Expected(':'.into())),
+            space1,
+            separated(1.., Round::parse, (";", space1)),
+        )
+            .map(|(_, _, id, _, _, rounds)| Game { id, rounds })
+            .parse_next(s)
+    }
+}
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {}: ", self.id)?;
+        for (i, round) in self.rounds.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", round)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn part1(
+    games: &[Game],
+    red_limit: u32,
+    green_limit: u32,
+    blue_limit: u32,
+) -> Result<u32, Error> {
+    games
+        .iter()
+        .filter(|game| {
+            game.rounds.iter().all(|round| {
+                round.red <= red_limit && round.green <= green_limit && round.blue <= blue_limit
+            })
+        })
+        .try_fold(0u32, |sum, game| sum.checked_add(game.id))
+        .ok_or_else(Error::overflow)
+}
+
+pub fn part2(games: &[Game]) -> Result<u32, Error> {
+    games
+        .iter()
+        .map(|game| {
+            let mut min_red = 0;
+            let mut min_green = 0;
+            let mut min_blue = 0;
+            game.rounds.iter().for_each(|round| {
+                min_red = min_red.max(round.red);
+                min_green = min_green.max(round.green);
+                min_blue = min_blue.max(round.blue);
+            });
+
+            min_red
+                .checked_mul(min_green)
+                .and_then(|power| power.checked_mul(min_blue))
+                .ok_or_else(Error::overflow)
+        })
+        .try_fold(0u32, |sum, power| {
+            sum.checked_add(power?).ok_or_else(Error::overflow)
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generate::Rng;
+
+    #[test]
+    fn test_parse_round() {
+        let mut x = "1 red, 3 blue, 11 green";
+        assert_eq!(
+            Round::parse(&mut x).unwrap(),
+            Round {
+                red: 1,
+                green: 11,
+                blue: 3
+            }
+        )
+    }
+
+    #[test]
+    fn test_parse_game() {
+        let mut x = "Game 1: 1 red, 3 blue, 11 green; 1 blue, 5 red; 3 blue, 5 green, 13 red; \
+                     6 red, 1 blue, 4 green; 16 red, 12 green";
+        assert_eq!(
+            Game::parse(&mut x).unwrap(),
+            Game {
+                id: 1,
+                rounds: vec![
+                    Round {
+                        red: 1,
+                        green: 11,
+                        blue: 3
+                    },
+                    Round {
+                        red: 5,
+                        green: 0,
+                        blue: 1
+                    },
+                    Round {
+                        red: 13,
+                        green: 5,
+                        blue: 3
+                    },
+                    Round {
+                        red: 6,
+                        green: 4,
+                        blue: 1
+                    },
+                    Round {
+                        red: 16,
+                        green: 12,
+                        blue: 0
+                    },
+                ]
+            }
+        )
+    }
+
+    #[test]
+    fn test_trailing_input() {
+        use crate::diagnostic::{with_mode, Mode};
+
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green junk\n";
+        assert!(Day2::parse(input).is_err());
+
+        let (games, warnings) = with_mode(Mode::Lenient, || Day2::parse(input));
+        let games = games.unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(Day2::part1(&games).unwrap(), 1);
+        assert_eq!(Day2::part2(&games).unwrap(), 24);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut rng = Rng::new(0);
+        for _ in 0..256 {
+            let rounds = (0..rng.between(1..=5))
+                .map(|_| {
+                    let [red, green, blue] = [0, 0, 0].map(|_| rng.between(0..=3) as u32);
+                    Round { red, green, blue }
+                })
+                .collect();
+            let game = Game {
+                id: rng.between(1..=1000) as u32,
+                rounds,
+            };
+            let input = game.to_string();
+            let parsed = Game::parse.parse(&input);
+            if game.rounds.contains(&Round::default()) {
+                assert!(parsed.is_err(), "{}", game);
+            } else {
+                assert_eq!(parsed.unwrap(), game);
+            }
+        }
+    }
+
+    #[test]
+    fn test_overflow() {
+        let games = Day2::parse("Game 1: 100000 red, 100000 green, 100000 blue\n").unwrap();
+        assert!(matches!(
+            part2(&games).unwrap_err().kind,
+            crate::error::ErrorKind::Overflow
+        ));
+        let games = Day2::parse("Game 4000000000: 1 red\nGame 4000000000: 1 red\n").unwrap();
+        assert!(matches!(
+            part1(&games, 12, 13, 14).unwrap_err().kind,
+            crate::error::ErrorKind::Overflow
+        ));
+    }
+
+    #[test]
+    fn test_empty_round() {
+        let error = Day2::parse("Game 1: 0 red, 0 blue\n").unwrap_err();
+        assert!(error.to_string().contains("The round doesn't show any cubes"));
+        assert_eq!(
+            Game::parse.parse("Game 1: 0 red, 2 blue").unwrap().to_string(),
+            "Game 1: 2 blue"
+        );
+    }
+
+    #[test]
+    fn test_repeated_colour() {
+        let error = Day2::parse("Game 1: 3 blue, 4 red, 5 blue\n").unwrap_err();
+        assert!(error.to_string().contains("blue is shown more than once"));
+    }
+}
@@ -0,0 +1,179 @@
+use std::{collections::BTreeSet, fmt};
+
+use color_eyre::Result;
+use winnow::{
+    ascii::{dec_uint, space1},
+    combinator::separated,
+    error::StrContext,
+    PResult, Parser,
+};
+
+use crate::{diagnostic, error::Error, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed<'a> = Vec<Card>;
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        diagnostic::parse_lines(Card::parse, input)
+    }
+
+    fn part1(cards: &Self::Parsed<'_>) -> Result<Self::Output> {
+        Ok(part1(cards)?)
+    }
+
+    fn part2(cards: &Self::Parsed<'_>) -> Result<Self::Output> {
+        Ok(part2(cards)?)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Card {
+    pub id: u32,
+    pub winning_numbers: BTreeSet<u32>,
+    pub given_numbers: BTreeSet<u32>,
+}
+
+impl Card {
+    pub fn parse(input: &mut &str) -> PResult<Self> {
+        (
+            "Card".context(StrContext::Expected("Card".into())),
+            space1,
+            dec_uint.context(StrContext::Label("card id")),
+            ":".context(StrContext::Expected(':'.into())),
+            space1,
+            separated(1.., dec_uint, space1).context(StrContext::Label("winning numbers")),
+            space1,
+            "|".context(StrContext::Expected('|'.into())),
+            space1,
+            separated(1.., dec_uint, space1).context(StrContext::Label("given numbers")),
+        )
+            .map(
+                |(_, _, id, _, _, winning_numbers, _, _, _, given_numbers)| Card {
+                    id,
+                    winning_numbers,
+                    given_numbers,
+                },
+            )
+            .parse_next(input)
+    }
+
+    fn score_part1(&self) -> Result<u32, Error> {
+        let intersection_size = self
+            .winning_numbers
+            .intersection(&self.given_numbers)
+            .count();
+        match intersection_size {
+            0 => Ok(0),
+            n => u32::try_from(n - 1)
+                .ok()
+                .and_then(|n| 1u32.checked_shl(n))
+                .ok_or_else(Error::overflow),
+        }
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |numbers: &BTreeSet<u32>| -> String {
+            let numbers: Vec<String> = numbers.iter().map(ToString::to_string).collect();
+            numbers.join(" ")
+        };
+        write!(
+            f,
+            "Card {}: {} | {}",
+            self.id,
+            join(&self.winning_numbers),
+            join(&self.given_numbers)
+        )
+    }
+}
+
+pub fn part1(cards: &[Card]) -> Result<u32, Error> {
+    cards.iter().try_fold(0u32, |sum, card| {
+        sum.checked_add(card.score_part1()?)
+            .ok_or_else(Error::overflow)
+    })
+}
+
+pub fn part2(cards: &[Card]) -> Result<u32, Error> {
+    let mut card_counts = vec![1u32; cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        // copies are won by id, so the cards have to be numbered in order from 1
+        if card.id as usize != i + 1 {
+            return Err(Error::invalid(format!(
+                "Card {} is out of order, expected card {}",
+                card.id,
+                i + 1
+            )));
+        }
+        let num_intersections = card
+            .winning_numbers
+            .intersection(&card.given_numbers)
+            .count();
+        let current_multiplier = card_counts[i];
+        for x in card_counts.iter_mut().skip(i + 1).take(num_intersections) {
+            *x = x.checked_add(current_multiplier).ok_or_else(Error::overflow)?;
+        }
+    }
+
+    card_counts
+        .into_iter()
+        .try_fold(0u32, u32::checked_add)
+        .ok_or_else(Error::overflow)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{error::ErrorKind, generate::Rng};
+
+    #[test]
+    fn test_part2_out_of_order() {
+        let cards = diagnostic::parse_lines(
+            Card::parse,
+            "Card 1: 41 48 | 83 41\nCard 3: 13 32 | 61 30\n",
+        )
+        .unwrap();
+        let error = part2(&cards).unwrap_err();
+        assert!(matches!(error.kind, ErrorKind::Invalid(_)));
+        assert_eq!(
+            error.to_string(),
+            "Card 3 is out of order, expected card 2"
+        );
+    }
+
+    #[test]
+    fn test_part1_overflow() {
+        let card = |matches: u32| Card {
+            id: 1,
+            winning_numbers: (0..matches).collect(),
+            given_numbers: (0..matches).collect(),
+        };
+        assert_eq!(part1(&[card(0), card(1), card(4)]).unwrap(), 9);
+        assert_eq!(part1(&[card(32)]).unwrap(), 1 << 31);
+        for cards in [vec![card(33)], vec![card(40)], vec![card(32), card(32)]] {
+            assert!(matches!(part1(&cards).unwrap_err().kind, ErrorKind::Overflow));
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut rng = Rng::new(0);
+        let mut numbers = |count| -> BTreeSet<u32> {
+            (0..rng.between(1..=count))
+                .map(|_| rng.between(0..=99) as u32)
+                .collect()
+        };
+        for id in 1..=256 {
+            let card = Card {
+                id,
+                winning_numbers: numbers(10),
+                given_numbers: numbers(25),
+            };
+            assert_eq!(Card::parse.parse(&card.to_string()).unwrap(), card);
+        }
+    }
+}
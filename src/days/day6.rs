@@ -0,0 +1,197 @@
+use std::fmt;
+
+use color_eyre::Result;
+use winnow::{
+    ascii::{dec_uint, digit1, space1},
+    combinator::separated,
+    error::StrContext,
+    PResult, Parser,
+};
+
+use crate::{diagnostic, error::Error, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    /// The races read as separate numbers for part 1, and as one big race for part 2.
+    type Parsed<'a> = (Vec<Game>, Game);
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        let part1 = diagnostic::parse(game_parser_part_1, input)?;
+        let part2 = diagnostic::parse(game_parser_part_2, input)?;
+        Ok((part1, part2))
+    }
+
+    fn part1((games, _): &Self::Parsed<'_>) -> Result<Self::Output> {
+        Ok(solve(games)?)
+    }
+
+    fn part2((_, game): &Self::Parsed<'_>) -> Result<Self::Output> {
+        Ok(solve(std::slice::from_ref(game))?)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Game {
+    pub time: u64,
+    pub record: u64,
+}
+
+/// Renders races as the input's table, with a column per race.
+pub fn render(games: &[Game]) -> String {
+    let (mut times, mut records) = ("Time:    ".to_string(), "Distance:".to_string());
+    for game in games {
+        let (time, record) = (game.time.to_string(), game.record.to_string());
+        let width = time.len().max(record.len()) + 2;
+        times.push_str(&format!("{:>width$}", time));
+        records.push_str(&format!("{:>width$}", record));
+    }
+    format!("{}\n{}\n", times, records)
+}
+
+/// Renders the race as an input with a single race.
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", render(std::slice::from_ref(self)))
+    }
+}
+
+pub fn game_parser_part_1(input: &mut &str) -> PResult<Vec<Game>> {
+    (
+        "Time:".context(StrContext::Expected("Time:".into())),
+        space1,
+        separated::<_, _, Vec<u64>, _, _, _, _>(1.., dec_uint::<_, u64, _>, space1),
+        diagnostic::line_end,
+        "Distance:".context(StrContext::Expected("Distance:".into())),
+        space1,
+        separated::<_, _, Vec<u64>, _, _, _, _>(1.., dec_uint::<_, u64, _>, space1),
+        diagnostic::line_end,
+    )
+        .try_map(
+            |(_, _, times, _, _, _, records, _): (_, _, Vec<u64>, _, _, _, Vec<u64>, _)| {
+                if times.len() != records.len() {
+                    return Err(Error::invalid(format!(
+                        "There are {} times but {} distances",
+                        times.len(),
+                        records.len()
+                    )));
+                }
+                Ok(times
+                    .iter()
+                    .zip(&records)
+                    .map(|(&time, &record)| Game { time, record })
+                    .collect())
+            },
+        )
+        .parse_next(input)
+}
+
+pub fn solve(input: &[Game]) -> Result<u64, Error> {
+    input
+        .iter()
+        .map(|Game { time, record }| {
+            // requirements:
+            // h + t == time
+            // h * t >= record
+            let mut ways = 0u64;
+            for h in 1..*time {
+                let t = time - h;
+                if h.checked_mul(t).ok_or_else(Error::overflow)? > *record {
+                    ways += 1;
+                }
+            }
+            Ok(ways)
+        })
+        .try_fold(1u64, |x, y: Result<u64, Error>| {
+            x.checked_mul(y?).ok_or_else(Error::overflow)
+        })
+}
+
+pub fn game_parser_part_2(input: &mut &str) -> PResult<Game> {
+    (
+        (
+            "Time:".context(StrContext::Expected("Time:".into())),
+            space1,
+            separated(1.., digit1, space1),
+            diagnostic::line_end,
+        )
+            .try_map(|(_, _, x, _): (_, _, String, _)| x.parse::<u64>()),
+        (
+            "Distance:".context(StrContext::Expected("Distance:".into())),
+            space1,
+            separated(1.., digit1, space1),
+            diagnostic::line_end,
+        )
+            .try_map(|(_, _, x, _): (_, _, String, _)| x.parse::<u64>()),
+    )
+        .map(|(time, record): (u64, u64)| Game { time, record })
+        .parse_next(input)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generate::Rng;
+
+    #[test]
+    fn test_line_endings() {
+        for input in [
+            "Time:      7  15   30\nDistance:  9  40  200\n",
+            "Time:      7  15   30\r\nDistance:  9  40  200\r\n",
+            "Time:      7  15   30\nDistance:  9  40  200",
+        ] {
+            let (games, game) = Day6::parse(input).unwrap();
+            assert_eq!(Day6::part1(&(games, game)).unwrap(), 288);
+            assert_eq!(game_parser_part_2.parse(input).unwrap().record, 940200);
+        }
+    }
+
+    #[test]
+    fn test_mismatched_races() {
+        let error = Day6::parse("Time: 7 15\nDistance: 9\n").unwrap_err();
+        assert!(
+            error.to_string().contains("There are 2 times but 1 distances"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(solve(&[Game { time: 0, record: 0 }]).unwrap(), 0);
+        let game = Game {
+            time: u64::MAX,
+            record: 0,
+        };
+        let e = solve(&[game]).unwrap_err();
+        assert!(matches!(e.kind, crate::error::ErrorKind::Overflow));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut rng = Rng::new(0);
+        for _ in 0..256 {
+            let games: Vec<Game> = (0..rng.between(1..=4))
+                .map(|_| Game {
+                    time: rng.between(0..=9999),
+                    record: rng.between(0..=9999),
+                })
+                .collect();
+            let input = render(&games);
+            assert_eq!(game_parser_part_1.parse(&input).unwrap(), games);
+
+            // part 2 reads the columns as one race, with their digits run together
+            let concat = |field: fn(&Game) -> u64| -> u64 {
+                let digits: String = games.iter().map(|game| field(game).to_string()).collect();
+                digits.parse().unwrap()
+            };
+            let game = Game {
+                time: concat(|game| game.time),
+                record: concat(|game| game.record),
+            };
+            assert_eq!(game_parser_part_2.parse(&input).unwrap(), game);
+            assert_eq!(game_parser_part_2.parse(&game.to_string()).unwrap(), game);
+        }
+    }
+}
@@ -0,0 +1,478 @@
+use color_eyre::Result;
+
+use std::{cmp::Ordering, collections::BTreeMap, fmt};
+
+use winnow::{
+    ascii::{dec_uint, space1},
+    combinator::{alt, cut_err, eof, not, preceded, repeat, terminated},
+    error::{ContextError, StrContext, StrContextValue},
+    PResult, Parser,
+};
+
+use crate::{diagnostic, error::Error, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    /// The hands read with `J` as a jack for part 1, and as a joker for part 2.
+    type Parsed<'a> = (Game, Game);
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        let game1 = diagnostic::parse(Game::parse_part1, input)?;
+        let game2 = diagnostic::parse(Game::parse_part2, input)?;
+        Ok((game1, game2))
+    }
+
+    fn part1((game, _): &Self::Parsed<'_>) -> Result<Self::Output> {
+        Ok(game.part1()?)
+    }
+
+    fn part2((_, game): &Self::Parsed<'_>) -> Result<Self::Output> {
+        Ok(game.part2()?)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Card {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Card {
+    pub fn parse<'a>(use_jokers: bool) -> impl Parser<&'a str, Self, ContextError> {
+        move |input: &mut &'a str| -> PResult<Self> {
+            alt((
+                "A".map(|_| Card::Ace),
+                "2".map(|_| Card::Two),
+                "3".map(|_| Card::Three),
+                "4".map(|_| Card::Four),
+                "5".map(|_| Card::Five),
+                "6".map(|_| Card::Six),
+                "7".map(|_| Card::Seven),
+                "8".map(|_| Card::Eight),
+                "9".map(|_| Card::Nine),
+                "T".map(|_| Card::Ten),
+                "J".map(|_| if use_jokers { Card::Joker } else { Card::Jack }),
+                "Q".map(|_| Card::Queen),
+                "K".map(|_| Card::King),
+            ))
+            .context(StrContext::Label("card"))
+            .context(StrContext::Expected(StrContextValue::Description(
+                "one of A, K, Q, J, T or 2-9",
+            )))
+            .parse_next(input)
+        }
+    }
+}
+
+/// Renders the card as its letter or digit. Jokers and jacks are both `J`.
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Card::Joker | Card::Jack => 'J',
+            Card::Two => '2',
+            Card::Three => '3',
+            Card::Four => '4',
+            Card::Five => '5',
+            Card::Six => '6',
+            Card::Seven => '7',
+            Card::Eight => '8',
+            Card::Nine => '9',
+            Card::Ten => 'T',
+            Card::Queen => 'Q',
+            Card::King => 'K',
+            Card::Ace => 'A',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum HandType {
+    HighCard(Card),
+    OnePair(Card),
+    TwoPair { major: Card, minor: Card },
+    ThreeOfAKind(Card),
+    FullHouse { major: Card, minor: Card },
+    FourOfAKind(Card),
+    FullSet(Card),
+}
+
+#[derive(Debug)]
+pub struct Hand {
+    pub hand_type: HandType,
+    pub hand: [Card; 5],
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Hand {}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let hand_cmp = self
+            .hand
+            .into_iter()
+            .zip(other.hand)
+            .find_map(|(x, y)| match x.cmp(&y) {
+                Ordering::Equal => None,
+                x => Some(x),
+            })
+            .unwrap_or(Ordering::Equal);
+        match (&self.hand_type, &other.hand_type) {
+            (HandType::HighCard(_), HandType::HighCard(_)) => hand_cmp,
+            (HandType::HighCard(_), HandType::OnePair(_)) => Ordering::Less,
+            (HandType::HighCard(_), HandType::TwoPair { .. }) => Ordering::Less,
+            (HandType::HighCard(_), HandType::ThreeOfAKind(_)) => Ordering::Less,
+            (HandType::HighCard(_), HandType::FullHouse { .. }) => Ordering::Less,
+            (HandType::HighCard(_), HandType::FourOfAKind(_)) => Ordering::Less,
+            (HandType::HighCard(_), HandType::FullSet(_)) => Ordering::Less,
+            (HandType::OnePair(_), HandType::HighCard(_)) => Ordering::Greater,
+            (HandType::OnePair(_), HandType::OnePair(_)) => hand_cmp,
+            (HandType::OnePair(_), HandType::TwoPair { .. }) => Ordering::Less,
+            (HandType::OnePair(_), HandType::ThreeOfAKind(_)) => Ordering::Less,
+            (HandType::OnePair(_), HandType::FullHouse { .. }) => Ordering::Less,
+            (HandType::OnePair(_), HandType::FourOfAKind(_)) => Ordering::Less,
+            (HandType::OnePair(_), HandType::FullSet(_)) => Ordering::Less,
+            (HandType::TwoPair { .. }, HandType::HighCard(_)) => Ordering::Greater,
+            (HandType::TwoPair { .. }, HandType::OnePair(_)) => Ordering::Greater,
+            (HandType::TwoPair { .. }, HandType::TwoPair { .. }) => hand_cmp,
+            (HandType::TwoPair { .. }, HandType::ThreeOfAKind(_)) => Ordering::Less,
+            (HandType::TwoPair { .. }, HandType::FullHouse { .. }) => Ordering::Less,
+            (HandType::TwoPair { .. }, HandType::FourOfAKind(_)) => Ordering::Less,
+            (HandType::TwoPair { .. }, HandType::FullSet(_)) => Ordering::Less,
+            (HandType::ThreeOfAKind(_), HandType::HighCard(_)) => Ordering::Greater,
+            (HandType::ThreeOfAKind(_), HandType::OnePair(_)) => Ordering::Greater,
+            (HandType::ThreeOfAKind(_), HandType::TwoPair { .. }) => Ordering::Greater,
+            (HandType::ThreeOfAKind(_), HandType::ThreeOfAKind(_)) => hand_cmp,
+            (HandType::ThreeOfAKind(_), HandType::FullHouse { .. }) => Ordering::Less,
+            (HandType::ThreeOfAKind(_), HandType::FourOfAKind(_)) => Ordering::Less,
+            (HandType::ThreeOfAKind(_), HandType::FullSet(_)) => Ordering::Less,
+            (HandType::FullHouse { .. }, HandType::HighCard(_)) => Ordering::Greater,
+            (HandType::FullHouse { .. }, HandType::OnePair(_)) => Ordering::Greater,
+            (HandType::FullHouse { .. }, HandType::TwoPair { .. }) => Ordering::Greater,
+            (HandType::FullHouse { .. }, HandType::ThreeOfAKind(_)) => Ordering::Greater,
+            (HandType::FullHouse { .. }, HandType::FullHouse { .. }) => hand_cmp,
+            (HandType::FullHouse { .. }, HandType::FourOfAKind(_)) => Ordering::Less,
+            (HandType::FullHouse { .. }, HandType::FullSet(_)) => Ordering::Less,
+            (HandType::FourOfAKind(_), HandType::HighCard(_)) => Ordering::Greater,
+            (HandType::FourOfAKind(_), HandType::OnePair(_)) => Ordering::Greater,
+            (HandType::FourOfAKind(_), HandType::TwoPair { .. }) => Ordering::Greater,
+            (HandType::FourOfAKind(_), HandType::ThreeOfAKind(_)) => Ordering::Greater,
+            (HandType::FourOfAKind(_), HandType::FullHouse { .. }) => Ordering::Greater,
+            (HandType::FourOfAKind(_), HandType::FourOfAKind(_)) => hand_cmp,
+            (HandType::FourOfAKind(_), HandType::FullSet(_)) => Ordering::Less,
+            (HandType::FullSet(_), HandType::HighCard(_)) => Ordering::Greater,
+            (HandType::FullSet(_), HandType::OnePair(_)) => Ordering::Greater,
+            (HandType::FullSet(_), HandType::TwoPair { .. }) => Ordering::Greater,
+            (HandType::FullSet(_), HandType::ThreeOfAKind(_)) => Ordering::Greater,
+            (HandType::FullSet(_), HandType::FullHouse { .. }) => Ordering::Greater,
+            (HandType::FullSet(_), HandType::FourOfAKind(_)) => Ordering::Greater,
+            (HandType::FullSet(_), HandType::FullSet(_)) => hand_cmp,
+        }
+    }
+}
+
+impl Hand {
+    pub fn parse<'a>(use_jokers: bool) -> impl Parser<&'a str, Self, ContextError> {
+        move |input: &mut &'a str| -> PResult<Self> {
+            (
+                Card::parse(use_jokers),
+                Card::parse(use_jokers),
+                Card::parse(use_jokers),
+                Card::parse(use_jokers),
+                Card::parse(use_jokers),
+            )
+                .try_map(|(a, b, c, d, e)| Hand::new([a, b, c, d, e]))
+                .parse_next(input)
+        }
+    }
+
+    pub fn new(hand: [Card; 5]) -> Result<Self, Error> {
+        let mut card_count: BTreeMap<Card, u8> = Default::default();
+        for card in &hand {
+            *card_count.entry(*card).or_default() += 1u8;
+        }
+        if let Some(joker_value) = card_count.get(&Card::Joker).cloned() {
+            // we need to normalize jokers to their highest value, which is always going to be
+            // the most frequent card available in the hand
+            if let Some(value) = card_count
+                .iter_mut()
+                .filter(|(k, _)| **k != Card::Joker)
+                .max_by(|x, y| x.1.cmp(&y.1))
+                .map(|(_, v)| v)
+            {
+                *value += joker_value;
+            } else {
+                card_count.insert(Card::Ace, joker_value);
+            }
+            card_count.remove(&Card::Joker);
+        }
+
+        // most frequent first, and highest first among equally frequent cards
+        let mut groups: Vec<(u8, Card)> = card_count.into_iter().map(|(k, v)| (v, k)).collect();
+        groups.sort_by(|x, y| y.cmp(x));
+
+        let hand_type = match groups[..] {
+            [(5, card)] => HandType::FullSet(card),
+            [(4, card), _] => HandType::FourOfAKind(card),
+            [(3, major), (2, minor)] => HandType::FullHouse { major, minor },
+            [(3, card), _, _] => HandType::ThreeOfAKind(card),
+            [(2, major), (2, minor), _] => HandType::TwoPair { major, minor },
+            [(2, card), _, _, _] => HandType::OnePair(card),
+            [(1, card), _, _, _, _] => HandType::HighCard(card),
+            _ => {
+                return Err(Error::invalid(format!(
+                    "{:?} can't be classified as a hand",
+                    hand
+                )))
+            }
+        };
+
+        Ok(Hand { hand_type, hand })
+    }
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for card in self.hand {
+            write!(f, "{}", card)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Round {
+    pub hand: Hand,
+    pub bid: u64,
+}
+
+impl fmt::Display for Round {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.hand, self.bid)
+    }
+}
+
+impl Round {
+    pub fn parse<'a>(use_jokers: bool) -> impl Parser<&'a str, Self, ContextError> {
+        move |input: &mut &'a str| -> PResult<Self> {
+            (
+                Hand::parse(use_jokers),
+                space1,
+                dec_uint.context(StrContext::Label("bid")),
+            )
+                .map(|(hand, _, bid)| Round { hand, bid })
+                .parse_next(input)
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Game {
+    pub rounds: Vec<Round>,
+}
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for round in &self.rounds {
+            writeln!(f, "{}", round)?;
+        }
+        Ok(())
+    }
+}
+
+impl Game {
+    pub fn parse_part1(input: &mut &str) -> PResult<Self> {
+        Self::rounds(false).parse_next(input)
+    }
+
+    pub fn parse_part2(input: &mut &str) -> PResult<Self> {
+        Self::rounds(true).parse_next(input)
+    }
+
+    fn rounds<'a>(use_jokers: bool) -> impl Parser<&'a str, Self, ContextError> {
+        move |input: &mut &'a str| -> PResult<Self> {
+            // once a line has started it has to be a whole round, so errors point into the line
+            // rather than at its start
+            repeat(
+                1..,
+                preceded(
+                    not(eof),
+                    cut_err(terminated(Round::parse(use_jokers), diagnostic::line_end)),
+                ),
+            )
+                .map(|rounds| Game { rounds })
+                .parse_next(input)
+        }
+    }
+
+    pub fn part1(&self) -> Result<u64, Error> {
+        self.winnings()
+    }
+
+    pub fn part2(&self) -> Result<u64, Error> {
+        self.winnings()
+    }
+
+    fn winnings(&self) -> Result<u64, Error> {
+        let mut rounds: Vec<&Round> = self.rounds.iter().collect();
+        rounds.sort_by(|x, y| x.hand.cmp(&y.hand));
+        rounds
+            .iter()
+            .enumerate()
+            .try_fold(0u64, |acc, (i, round)| {
+                (i as u64 + 1)
+                    .checked_mul(round.bid)
+                    .and_then(|winnings| acc.checked_add(winnings))
+            })
+            .ok_or_else(Error::overflow)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{error::ErrorKind, generate::Rng};
+
+    #[test]
+    fn test_parse1() {
+        let game = Game::parse_part1
+            .parse(include_str!("../../examples/day07/example.txt"))
+            .expect("Failed to parse input");
+        let actual = Game {
+            rounds: vec![
+                Round {
+                    hand: Hand {
+                        hand_type: HandType::OnePair(Card::Three),
+                        hand: [Card::Three, Card::Two, Card::Ten, Card::Three, Card::King],
+                    },
+                    bid: 765,
+                },
+                Round {
+                    hand: Hand {
+                        hand_type: HandType::ThreeOfAKind(Card::Five),
+                        hand: [Card::Ten, Card::Five, Card::Five, Card::Jack, Card::Five],
+                    },
+                    bid: 684,
+                },
+                Round {
+                    hand: Hand {
+                        hand_type: HandType::TwoPair {
+                            major: Card::King,
+                            minor: Card::Seven,
+                        },
+                        hand: [Card::King, Card::King, Card::Six, Card::Seven, Card::Seven],
+                    },
+                    bid: 28,
+                },
+                Round {
+                    hand: Hand {
+                        hand_type: HandType::TwoPair {
+                            major: Card::Jack,
+                            minor: Card::Ten,
+                        },
+                        hand: [Card::King, Card::Ten, Card::Jack, Card::Jack, Card::Ten],
+                    },
+                    bid: 220,
+                },
+                Round {
+                    hand: Hand {
+                        hand_type: HandType::ThreeOfAKind(Card::Queen),
+                        hand: [Card::Queen, Card::Queen, Card::Queen, Card::Jack, Card::Ace],
+                    },
+                    bid: 483,
+                },
+            ],
+        };
+        assert_eq!(&game, &actual);
+    }
+
+    #[test]
+    fn test_hand_types() {
+        let hand = |cards: &str| Hand::parse(true).parse(cards).unwrap().hand_type;
+        assert_eq!(hand("JJJJJ"), HandType::FullSet(Card::Ace));
+        assert_eq!(hand("KTJJT"), HandType::FourOfAKind(Card::Ten));
+        assert_eq!(
+            hand("KKTJT"),
+            HandType::FullHouse {
+                major: Card::King,
+                minor: Card::Ten
+            }
+        );
+        assert_eq!(hand("23456"), HandType::HighCard(Card::Six));
+    }
+
+    #[test]
+    fn test_winnings_overflow() {
+        let game = Game::parse_part1
+            .parse("32T3K 1\nKK677 18446744073709551615\n")
+            .unwrap();
+        let error = game.part1().unwrap_err();
+        assert!(matches!(error.kind, ErrorKind::Overflow));
+    }
+
+    #[test]
+    fn test_line_endings() {
+        let example = include_str!("../../examples/day07/example.txt");
+        for input in [
+            example.to_string(),
+            example.replace('\n', "\r\n"),
+            example.trim_end().to_string(),
+        ] {
+            let game = Game::parse_part1.parse(&input).unwrap();
+            assert_eq!(game.part1().unwrap(), 6440);
+            let game = Game::parse_part2.parse(&input).unwrap();
+            assert_eq!(game.part2().unwrap(), 5905);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        use Card::*;
+
+        let mut rng = Rng::new(0);
+        let mut game = |cards: &[Card]| -> Game {
+            let rounds = (0..rng.between(1..=20))
+                .map(|_| Round {
+                    hand: Hand::new([(); 5].map(|_| *rng.choose(cards))).unwrap(),
+                    bid: rng.between(0..=1000),
+                })
+                .collect();
+            Game { rounds }
+        };
+        let ranks = [Two, Three, Four, Five, Six, Seven, Eight, Nine, Ten, Queen, King, Ace];
+        for _ in 0..256 {
+            let with_jacks = game(&[&ranks[..], &[Jack]].concat());
+            assert_eq!(Game::parse_part1.parse(&with_jacks.to_string()).unwrap(), with_jacks);
+            let with_jokers = game(&[&ranks[..], &[Joker]].concat());
+            assert_eq!(
+                Game::parse_part2.parse(&with_jokers.to_string()).unwrap(),
+                with_jokers
+            );
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::runner::Solver;
+
+/// Declares each day's module and registers its solution with the runner.
+macro_rules! register {
+    ($($day:literal => $module:ident::$solution:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Every registered solver, in the order they were declared.
+        pub static SOLVERS: &[Solver] = &[
+            $(Solver::new::<$module::$solution>(stringify!($module), $day),)*
+        ];
+    };
+}
+
+register! {
+    1 => day1::Day1,
+    1 => day1_const::Day1Const,
+    2 => day2::Day2,
+    4 => day4::Day4,
+    6 => day6::Day6,
+    7 => day7::Day7,
+}
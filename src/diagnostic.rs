@@ -0,0 +1,423 @@
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+};
+
+use color_eyre::{Report, Result};
+
+use crate::error::Error;
+use winnow::{
+    ascii::line_ending,
+    combinator::{alt, eof},
+    error::{ContextError, StrContext},
+    PResult, Parser,
+};
+
+/// Where and why parsing an input failed, rendered with the offending line and a caret under the
+/// byte parsing stopped at:
+///
+/// ```text
+/// Failed to parse input at line 3, column 29
+///   |
+/// 3 | Game 3: 8 green, 6 blue, 20 rex
+///   |                             ^
+///   = invalid colour
+///   = expected `red`, `green` or `blue`
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// 1-based line number in the whole input.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub source_line: String,
+    /// What was being parsed, from [`StrContext::Label`]s.
+    pub labels: Vec<String>,
+    /// What would have been accepted, from [`StrContext::Expected`]s.
+    pub expected: Vec<String>,
+    /// The error that stopped parsing, for input that was well-formed but invalid.
+    pub cause: Option<String>,
+}
+
+impl Diagnostic {
+    /// Builds a diagnostic for a parse of `input` that failed at byte `offset`.
+    pub fn new(input: &str, offset: usize, error: &ContextError) -> Self {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |x| x + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |x| offset + x);
+        let source_line = input[line_start..line_end].trim_end_matches('\r');
+
+        let mut labels = Vec::new();
+        let mut expected = Vec::new();
+        for context in error.context() {
+            match context {
+                StrContext::Label(label) => labels.push(label.to_string()),
+                StrContext::Expected(value) => expected.push(value.to_string()),
+                _ => {}
+            }
+        }
+
+        Diagnostic {
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            source_line: source_line.to_string(),
+            labels,
+            expected,
+            cause: error.cause().map(ToString::to_string),
+        }
+    }
+
+    /// Builds a diagnostic for input left over at byte `offset` after a successful parse.
+    pub fn trailing(input: &str, offset: usize) -> Self {
+        Diagnostic {
+            cause: Some("unexpected input after the end".to_string()),
+            ..Diagnostic::new(input, offset, &ContextError::new())
+        }
+    }
+
+    /// Builds a diagnostic for a blank `line` where a record was expected.
+    pub fn blank(line: &str) -> Self {
+        Diagnostic {
+            cause: Some("unexpected blank line".to_string()),
+            ..Diagnostic::new(line, 0, &ContextError::new())
+        }
+    }
+
+    /// Moves the diagnostic down by `lines`, for inputs that are parsed a line at a time.
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Failed to parse input at line {}, column {}",
+            self.line, self.column
+        )?;
+        self.fmt_source(f)
+    }
+}
+
+impl Diagnostic {
+    /// Writes the offending line with a caret under the column, followed by the notes.
+    fn fmt_source(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))?;
+        for label in &self.labels {
+            write!(f, "\n{} = invalid {}", gutter, label)?;
+        }
+        if !self.expected.is_empty() {
+            write!(f, "\n{} = expected ", gutter)?;
+            for (i, expected) in self.expected.iter().enumerate() {
+                match i {
+                    0 => {}
+                    i if i == self.expected.len() - 1 => write!(f, " or ")?,
+                    _ => write!(f, ", ")?,
+                }
+                write!(f, "{}", expected)?;
+            }
+        }
+        if let Some(cause) = &self.cause {
+            write!(f, "\n{} = {}", gutter, cause)?;
+        }
+        Ok(())
+    }
+}
+
+/// A diagnostic for input that was ignored by a [`Mode::Lenient`] parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning(pub Diagnostic);
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Ignored input at line {}, column {}",
+            self.0.line, self.0.column
+        )?;
+        self.0.fmt_source(f)
+    }
+}
+
+/// What [`parse`] and [`parse_lines`] do with input that's left over after a successful parse.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// Fail with a [`Diagnostic`] pointing at the leftover input.
+    #[default]
+    Strict,
+    /// Ignore the leftover input, recording a [`Warning`].
+    Lenient,
+}
+
+thread_local! {
+    // solvers don't take a mode, so it's set around their parse by `with_mode`
+    static MODE: Cell<Mode> = const { Cell::new(Mode::Strict) };
+    static WARNINGS: RefCell<Vec<Warning>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Calls `f` with [`parse`] and [`parse_lines`] in `mode`, returning its result along with the
+/// warnings about ignored input.
+pub fn with_mode<T>(mode: Mode, f: impl FnOnce() -> T) -> (T, Vec<Warning>) {
+    // puts the thread back as it was even if `f` panics, as threads may be reused afterwards
+    struct Reset(Mode);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            MODE.set(self.0);
+            WARNINGS.take();
+        }
+    }
+
+    let _reset = Reset(MODE.replace(mode));
+    let output = f();
+    (output, WARNINGS.take())
+}
+
+/// Parses all of `input` with `parser`, reporting failures as an [`Error`] with a [`Diagnostic`].
+///
+/// Input left over after `parser` succeeds is handled according to the current [`Mode`].
+pub fn parse<'a, O>(
+    mut parser: impl Parser<&'a str, O, ContextError>,
+    input: &'a str,
+) -> Result<O> {
+    parse_all(&mut parser, input, 0)
+}
+
+/// The one place parsers are run, so that every day treats leftover input the same way.
+fn parse_all<'a, O>(
+    parser: &mut impl Parser<&'a str, O, ContextError>,
+    input: &'a str,
+    lines: usize,
+) -> Result<O> {
+    let mut remaining = input;
+    let result = parser.parse_next(&mut remaining);
+    let offset = input.len() - remaining.len();
+    match result {
+        Ok(output) if remaining.is_empty() => Ok(output),
+        Ok(output) => {
+            ignore(Diagnostic::trailing(input, offset).offset_lines(lines))?;
+            Ok(output)
+        }
+        Err(e) => {
+            let diagnostic = Diagnostic::new(input, offset, &e.into_inner().unwrap_or_default());
+            Err(Report::new(Error::parse(diagnostic.offset_lines(lines))))
+        }
+    }
+}
+
+/// Handles input that the parser didn't take according to the current [`Mode`], failing with
+/// `diagnostic` or recording it as a [`Warning`].
+fn ignore(diagnostic: Diagnostic) -> Result<()> {
+    if MODE.get() == Mode::Strict {
+        return Err(Report::new(Error::parse(diagnostic)));
+    }
+    let warning = Warning(diagnostic);
+    // days that parse the input more than once would otherwise warn about it each time
+    WARNINGS.with_borrow_mut(|warnings| {
+        if !warnings.contains(&warning) {
+            warnings.push(warning);
+        }
+    });
+    Ok(())
+}
+
+/// The end of a line: `\n`, `\r\n`, or the end of the input for a last line without a newline.
+pub fn line_end<'a>(input: &mut &'a str) -> PResult<&'a str> {
+    alt((line_ending, eof))
+        .context(StrContext::Expected("end of line".into()))
+        .parse_next(input)
+}
+
+/// Parses each line of `input` with `parser`, reporting failures as an [`Error`] with a
+/// [`Diagnostic`] that has the line's number in the whole input.
+///
+/// A single blank line at the end is allowed, other blank lines are handled according to the
+/// current [`Mode`] like leftover input.
+pub fn parse_lines<'a, O>(
+    mut parser: impl Parser<&'a str, O, ContextError>,
+    input: &'a str,
+) -> Result<Vec<O>> {
+    let mut lines: Vec<&str> = input.lines().collect();
+    if lines.last() == Some(&"") {
+        lines.pop();
+    }
+
+    let mut output = Vec::with_capacity(lines.len());
+    for (i, line) in lines.into_iter().enumerate() {
+        if line.is_empty() {
+            ignore(Diagnostic::blank(line).offset_lines(i))?;
+            continue;
+        }
+        output.push(parse_all(&mut parser, line, i)?);
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::ErrorKind;
+    use winnow::{ascii::dec_uint, combinator::repeat};
+
+    fn colour(input: &mut &str) -> PResult<&'static str> {
+        alt((
+            "red".value("red"),
+            "green".value("green"),
+            "blue".value("blue"),
+        ))
+        .context(StrContext::Label("colour"))
+        .context(StrContext::Expected("red".into()))
+        .context(StrContext::Expected("green".into()))
+        .context(StrContext::Expected("blue".into()))
+        .parse_next(input)
+    }
+
+    fn cubes(input: &mut &str) -> PResult<(u32, &'static str)> {
+        (dec_uint, " ", colour)
+            .map(|(n, _, c)| (n, c))
+            .parse_next(input)
+    }
+
+    fn diagnostic(report: &Report) -> &Diagnostic {
+        match &Error::find(report).unwrap().kind {
+            ErrorKind::Parse(diagnostic) => diagnostic,
+            kind => panic!("Expected a parse error, got {:?}", kind),
+        }
+    }
+
+    #[test]
+    fn test_parse_lines_reports_line_and_column() {
+        let (error, _) = with_mode(Mode::Lenient, || {
+            parse_lines(cubes, "1 red\n2 blue\n\n20 rex\n")
+        });
+        let error = error.unwrap_err();
+        let diagnostic = diagnostic(&error);
+        assert_eq!(
+            diagnostic,
+            &Diagnostic {
+                line: 4,
+                column: 4,
+                source_line: "20 rex".to_string(),
+                labels: vec!["colour".to_string()],
+                expected: vec![
+                    "`red`".to_string(),
+                    "`green`".to_string(),
+                    "`blue`".to_string()
+                ],
+                cause: None,
+            }
+        );
+        assert_eq!(
+            diagnostic.to_string(),
+            "Failed to parse input at line 4, column 4\n\
+             \x20 |\n\
+             4 | 20 rex\n\
+             \x20 |    ^\n\
+             \x20 = invalid colour\n\
+             \x20 = expected `red`, `green` or `blue`"
+        );
+    }
+
+    #[test]
+    fn test_parse_reports_position_in_whole_input() {
+        let error = parse((cubes, "\n", cubes, "\n"), "1 red\r\n2 blue\n").unwrap_err();
+        let diagnostic = diagnostic(&error);
+        assert_eq!((diagnostic.line, diagnostic.column), (1, 6));
+        assert_eq!(diagnostic.source_line, "1 red");
+    }
+
+    #[test]
+    fn test_parse_reports_end_of_input() {
+        let error = parse((cubes, "\n"), "1 red").unwrap_err();
+        let diagnostic = diagnostic(&error);
+        assert_eq!((diagnostic.line, diagnostic.column), (1, 6));
+    }
+
+    #[test]
+    fn test_line_endings() {
+        let lines = |input| -> Vec<(u32, &str)> {
+            repeat(1.., (cubes, line_end).map(|(cubes, _)| cubes))
+                .parse(input)
+                .unwrap()
+        };
+        let expected = vec![(1, "red"), (2, "blue")];
+        assert_eq!(lines("1 red\n2 blue\n"), expected);
+        assert_eq!(lines("1 red\r\n2 blue\r\n"), expected);
+        assert_eq!(lines("1 red\n2 blue"), expected);
+
+        let expected = vec![vec![(1, "red")], vec![(2, "blue")]];
+        for input in ["1 red\n2 blue\n", "1 red\r\n2 blue\r\n", "1 red\n2 blue"] {
+            let actual = parse_lines(cubes.map(|x| vec![x]), input).unwrap();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_blank_lines() {
+        let input = "1 red\n\n\n2 blue\n";
+        let error = parse_lines(cubes, input).unwrap_err();
+        let diagnostic = diagnostic(&error);
+        assert_eq!((diagnostic.line, diagnostic.column), (2, 1));
+        assert_eq!(diagnostic.cause.as_deref(), Some("unexpected blank line"));
+
+        let (parsed, warnings) = with_mode(Mode::Lenient, || parse_lines(cubes, input));
+        assert_eq!(parsed.unwrap(), vec![(1, "red"), (2, "blue")]);
+        let lines: Vec<usize> = warnings.iter().map(|warning| warning.0.line).collect();
+        assert_eq!(lines, [2, 3]);
+
+        // only one blank line is allowed at the end
+        assert_eq!(parse_lines(cubes, "1 red\n\n").unwrap(), [(1, "red")]);
+        assert_eq!(parse_lines(cubes, "1 red\r\n\r\n").unwrap(), [(1, "red")]);
+        assert!(parse_lines(cubes, "1 red\n\n\n").is_err());
+        assert_eq!(parse_lines(cubes, "").unwrap(), []);
+    }
+
+    #[test]
+    fn test_trailing_input() {
+        let input = "1 red\n2 blue and more\n";
+        let error = parse_lines(cubes, input).unwrap_err();
+        let diagnostic = diagnostic(&error);
+        assert_eq!((diagnostic.line, diagnostic.column), (2, 7));
+        assert_eq!(
+            diagnostic.cause.as_deref(),
+            Some("unexpected input after the end")
+        );
+
+        let (parsed, warnings) = with_mode(Mode::Lenient, || parse_lines(cubes, input));
+        assert_eq!(parsed.unwrap(), vec![(1, "red"), (2, "blue")]);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].to_string(),
+            "Ignored input at line 2, column 7\n\
+             \x20 |\n\
+             2 | 2 blue and more\n\
+             \x20 |       ^\n\
+             \x20 = unexpected input after the end"
+        );
+
+        // parsing the same input twice only warns about it once
+        let (_, warnings) = with_mode(Mode::Lenient, || {
+            (parse_lines(cubes, input), parse_lines(cubes, input))
+        });
+        assert_eq!(warnings.len(), 1);
+
+        // the mode only lasts for the call, even one that panics
+        assert!(parse_lines(cubes, input).is_err());
+        let panicked = std::panic::catch_unwind(|| {
+            with_mode(Mode::Lenient, || {
+                parse_lines(cubes, input).unwrap();
+                panic!("solver bug");
+            })
+        });
+        assert!(panicked.is_err());
+        assert!(parse_lines(cubes, input).is_err());
+        assert_eq!(with_mode(Mode::Strict, || ()).1, []);
+    }
+}
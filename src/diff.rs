@@ -0,0 +1,141 @@
+use crate::runner::{Part, Solver};
+
+/// Each solver's answer to a part, or the error it failed with.
+pub type Results = Vec<(&'static str, Result<String, String>)>;
+
+/// Solvers for the same day that gave different answers to a part of an input.
+#[derive(Debug)]
+pub struct Disagreement {
+    pub part: Part,
+    pub results: Results,
+    /// The first line that the solvers disagree on, if it could be found.
+    pub line: Option<Line>,
+}
+
+#[derive(Debug)]
+pub struct Line {
+    /// 1-based line number in the input.
+    pub number: usize,
+    pub text: String,
+    /// What the solvers answered for this line on its own, or for the input up to and including
+    /// it if the line only makes them disagree in context.
+    pub results: Results,
+}
+
+/// Runs each of `solvers` on `input`, returning where they disagree on `part`, if they do.
+///
+/// Solvers agree if they all give the same answer, or all fail.
+pub fn diff(solvers: &[&'static Solver], input: &str, part: Part) -> Option<Disagreement> {
+    let results = run(solvers, input, part);
+    if agree(&results) {
+        return None;
+    }
+
+    // most days solve each line on its own, so try the lines by themselves first, then fall back
+    // to the shortest prefix of lines that the solvers disagree on
+    let lines: Vec<&str> = input.lines().collect();
+    let line = lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| (i, run(solvers, line, part)))
+        .find(|(_, results)| !agree(results))
+        .or_else(|| {
+            (0..lines.len())
+                .map(|i| (i, run(solvers, &lines[..=i].join("\n"), part)))
+                .find(|(_, results)| !agree(results))
+        })
+        .map(|(i, results)| Line {
+            number: i + 1,
+            text: lines[i].to_string(),
+            results,
+        });
+
+    Some(Disagreement {
+        part,
+        results,
+        line,
+    })
+}
+
+fn run(solvers: &[&'static Solver], input: &str, part: Part) -> Results {
+    solvers
+        .iter()
+        .map(|solver| {
+            let answer = solver
+                .run(input, &[part])
+                .map(|mut answers| answers.remove(0))
+                .map_err(|e| format!("{:#}", e));
+            (solver.name, answer)
+        })
+        .collect()
+}
+
+fn agree(results: &Results) -> bool {
+    let first = results.first().map(|(_, answer)| answer.as_ref().ok());
+    results
+        .iter()
+        .all(|(_, answer)| Some(answer.as_ref().ok()) == first)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{days::SOLVERS, generate::generate};
+
+    fn day1_solvers() -> Vec<&'static Solver> {
+        SOLVERS.iter().filter(|solver| solver.day == 1).collect()
+    }
+
+    #[test]
+    fn test_day1_solvers_agree() {
+        let solvers = day1_solvers();
+        assert!(solvers.len() > 1);
+        for seed in 0..20 {
+            let input = generate(1, seed, 20).unwrap();
+            for part in Part::ALL {
+                let disagreement = diff(&solvers, &input, part);
+                assert!(disagreement.is_none(), "{:?}", disagreement);
+            }
+        }
+    }
+
+    #[test]
+    fn test_finds_offending_line() {
+        // a stand-in for a second implementation that gets lines with a 9 wrong
+        struct Buggy;
+
+        impl crate::Solution for Buggy {
+            type Parsed<'a> = &'a str;
+            type Output = u32;
+
+            fn parse(input: &str) -> color_eyre::Result<&str> {
+                Ok(input)
+            }
+
+            fn part1(input: &&str) -> color_eyre::Result<u32> {
+                let answer = crate::days::day1::part1(input)?;
+                Ok(answer + input.matches('9').count() as u32)
+            }
+
+            fn part2(input: &&str) -> color_eyre::Result<u32> {
+                crate::days::day1::part2(input)
+            }
+        }
+        static BUGGY: Solver = Solver::new::<Buggy>("buggy", 1);
+
+        let solvers = [day1_solvers()[0], &BUGGY];
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e9f\ntreb7uchet\n";
+        assert!(diff(&solvers, input, Part::Two).is_none());
+
+        let disagreement = diff(&solvers, input, Part::One).unwrap();
+        let line = disagreement.line.unwrap();
+        assert_eq!((line.number, line.text.as_str()), (3, "a1b2c3d4e9f"));
+        assert_eq!(
+            line.results,
+            [
+                ("day1", Ok("19".to_string())),
+                ("buggy", Ok("20".to_string()))
+            ]
+        );
+    }
+}
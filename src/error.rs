@@ -0,0 +1,149 @@
+use std::{fmt, path::PathBuf};
+
+use color_eyre::Report;
+
+use crate::diagnostic::Diagnostic;
+
+/// A failure while solving a puzzle, with as much context as is known about where it happened.
+///
+/// Solvers return these inside an [`eyre::Report`](color_eyre::Report), so code that cares about
+/// the kind of failure can find it with [`Error::find`].
+#[derive(Debug)]
+pub struct Error {
+    pub kind: ErrorKind,
+    pub day: Option<u32>,
+    /// 1-based line number in the input.
+    pub line: Option<usize>,
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The input doesn't follow the puzzle's format.
+    Parse(Box<Diagnostic>),
+    /// The input has the right format, but doesn't make sense for the puzzle.
+    Invalid(String),
+    /// An answer or intermediate result didn't fit in its integer type.
+    Overflow,
+    /// There's no input to solve.
+    MissingInput(PathBuf),
+    /// The solver panicked, with this message.
+    Panic(String),
+}
+
+impl Error {
+    pub fn new(kind: ErrorKind) -> Self {
+        Error {
+            kind,
+            day: None,
+            line: None,
+        }
+    }
+
+    pub fn parse(diagnostic: Diagnostic) -> Self {
+        let line = diagnostic.line;
+        Error::new(ErrorKind::Parse(Box::new(diagnostic))).at_line(line)
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        Error::new(ErrorKind::Invalid(message.into()))
+    }
+
+    pub fn overflow() -> Self {
+        Error::new(ErrorKind::Overflow)
+    }
+
+    pub fn missing_input(path: impl Into<PathBuf>) -> Self {
+        Error::new(ErrorKind::MissingInput(path.into()))
+    }
+
+    pub fn panic(message: impl Into<String>) -> Self {
+        Error::new(ErrorKind::Panic(message.into()))
+    }
+
+    pub fn for_day(mut self, day: u32) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Finds the [`Error`] in a report's chain of causes, if there is one.
+    pub fn find(report: &Report) -> Option<&Error> {
+        report.chain().find_map(|e| e.downcast_ref::<Error>())
+    }
+}
+
+/// Records `day` on the [`Error`] in `report`, if it doesn't already know its day.
+pub fn with_day(mut report: Report, day: u32) -> Report {
+    if let Some(error) = report.downcast_mut::<Error>() {
+        error.day.get_or_insert(day);
+    }
+    report
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // parse diagnostics already show where they happened
+        if let ErrorKind::Parse(diagnostic) = &self.kind {
+            if let Some(day) = self.day {
+                write!(f, "Day {}: ", day)?;
+            }
+            return write!(f, "{}", diagnostic);
+        }
+
+        match (self.day, self.line) {
+            (Some(day), Some(line)) => write!(f, "Day {}, line {}: ", day, line)?,
+            (Some(day), None) => write!(f, "Day {}: ", day)?,
+            (None, Some(line)) => write!(f, "Line {}: ", line)?,
+            (None, None) => {}
+        }
+        match &self.kind {
+            ErrorKind::Parse(_) => unreachable!(),
+            ErrorKind::Invalid(message) => write!(f, "{}", message),
+            ErrorKind::Overflow => write!(f, "Arithmetic overflow"),
+            ErrorKind::MissingInput(path) => write!(
+                f,
+                "No input: {} does not exist. Pass --input <path>, or --input - to read from stdin",
+                path.display()
+            ),
+            ErrorKind::Panic(message) => write!(f, "Panicked: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = Error::invalid("card 3 is out of order").at_line(3);
+        assert_eq!(error.to_string(), "Line 3: card 3 is out of order");
+        assert_eq!(
+            Error::overflow().for_day(7).to_string(),
+            "Day 7: Arithmetic overflow"
+        );
+    }
+
+    #[test]
+    fn test_find_through_context() {
+        use color_eyre::eyre::WrapErr;
+
+        let report: Report = Err::<(), _>(Error::overflow())
+            .wrap_err("Failed to solve")
+            .unwrap_err();
+        assert!(matches!(
+            Error::find(&report).map(|e| &e.kind),
+            Some(ErrorKind::Overflow)
+        ));
+
+        let report = with_day(Report::new(Error::overflow()), 2);
+        assert_eq!(Error::find(&report).unwrap().day, Some(2));
+    }
+}
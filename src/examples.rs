@@ -0,0 +1,25 @@
+//! Tests for the examples under `examples/dayNN/`, one per recorded answer, written by `build.rs`.
+
+use std::path::Path;
+
+use crate::{answers, runner::Part};
+
+/// Checks every solver for the example's day against the recorded answer to `part`.
+fn check(dir: &str, file_name: &str, part: Part) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("examples")
+        .join(dir);
+    let path = dir.join(file_name);
+    let checks: Vec<_> = answers::verify(&dir)
+        .unwrap()
+        .into_iter()
+        .filter(|check| check.input == path && check.part == part)
+        .collect();
+
+    assert!(!checks.is_empty(), "No solver for {}", path.display());
+    for check in checks {
+        assert!(check.passed(), "{}", check);
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
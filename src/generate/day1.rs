@@ -0,0 +1,28 @@
+use super::Rng;
+
+/// Calibration lines mixing letters, digits (including `0`) and spelled-out digits, some of them
+/// overlapping like `eightwo`. Every line has at least one digit, so both parts have an answer.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const WORDS: [&str; 13] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "eightwo",
+        "oneight", "twone", "zero",
+    ];
+
+    let mut input = String::new();
+    for _ in 0..size {
+        let digit_at = rng.below(6);
+        for i in 0..6 {
+            if i == digit_at {
+                input.push(char::from(b'1' + rng.below(9) as u8));
+                continue;
+            }
+            match rng.below(4) {
+                0 => input.push_str(rng.choose::<&str>(&WORDS)),
+                1 => input.push(char::from(b'0' + rng.below(10) as u8)),
+                _ => input.push(char::from(b'a' + rng.below(26) as u8)),
+            }
+        }
+        input.push('\n');
+    }
+    input
+}
@@ -0,0 +1,22 @@
+use super::Rng;
+
+/// Games of up to six rounds, each showing one to three colours in any order. Counts go up to 20,
+/// so some games are impossible with part 1's limits of 12 red, 13 green and 14 blue.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for id in 1..=size {
+        let rounds: Vec<String> = (0..rng.between(1..=6))
+            .map(|_| {
+                let mut colours = ["red", "green", "blue"];
+                rng.shuffle(&mut colours);
+                colours[..rng.between(1..=3) as usize]
+                    .iter()
+                    .map(|colour| format!("{} {}", rng.between(1..=20), colour))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        input.push_str(&format!("Game {}: {}\n", id, rounds.join("; ")));
+    }
+    input
+}
@@ -0,0 +1,34 @@
+use super::Rng;
+
+/// Scratchcards with 10 winning numbers and 25 given numbers, numbered in order.
+///
+/// Copies won in part 2 grow exponentially with the number of matches, so matches are kept to
+/// at most 5, and every 15 cards end with a run of 5 cards that match nothing. That stops copies
+/// carrying over, so part 2's answer fits in a `u32` for a few hundred thousand cards.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    let mut numbers: Vec<u64> = (1..=99).collect();
+    for id in 1..=size {
+        let matches = if id % 15 < 10 { rng.below(6) } else { 0 } as usize;
+        rng.shuffle(&mut numbers);
+        let (winning, others) = numbers.split_at(10);
+        let mut given: Vec<u64> = winning[..matches]
+            .iter()
+            .chain(&others[..25 - matches])
+            .copied()
+            .collect();
+        rng.shuffle(&mut given);
+
+        let format = |numbers: &[u64]| -> String {
+            let numbers: Vec<String> = numbers.iter().map(|x| format!("{:>2}", x)).collect();
+            numbers.join(" ")
+        };
+        input.push_str(&format!(
+            "Card {:>3}: {} | {}\n",
+            id,
+            format(winning),
+            format(&given)
+        ));
+    }
+    input
+}
@@ -0,0 +1,53 @@
+use super::Rng;
+use crate::days::day6::{self, Game};
+
+/// The most digits a race's time can have, so that the best distance fits in a `u64`.
+const MAX_DIGITS: u32 = 9;
+
+/// Up to four races, or more if they'd need more than [`MAX_DIGITS`] each, whose times have `size`
+/// digits between them. Part 2 reads them as one race with a `size`-digit time, so this is the
+/// way to make the brute-force solver work hard. Sizes above 19 overflow the `u64`s it uses.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let races = size.clamp(1, 4).max(size.div_ceil(MAX_DIGITS as usize));
+    let mut digits = vec![1; races];
+    for _ in races..size {
+        let short: Vec<usize> = (0..races).filter(|&i| digits[i] < MAX_DIGITS).collect();
+        digits[*rng.choose(&short)] += 1;
+    }
+
+    let games: Vec<Game> = digits
+        .into_iter()
+        .map(|digits| {
+            let min = 10u64.pow(digits - 1).max(2);
+            let time = rng.between(min..=10u64.pow(digits) - 1);
+            // holding the button for half the race goes furthest, so this record can be beaten
+            let best = (time / 2) * (time - time / 2);
+            Game {
+                time,
+                record: rng.below(best),
+            }
+        })
+        .collect();
+    day6::render(&games)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::days::day6::game_parser_part_1;
+    use winnow::Parser;
+
+    #[test]
+    fn test_huge_times() {
+        for size in [9, 10, 20, 40, 100] {
+            let input = generate(&mut Rng::new(0), size);
+            let games = game_parser_part_1.parse(&input).unwrap();
+            let digits: usize = games.iter().map(|game| game.time.to_string().len()).sum();
+            assert_eq!(digits, size);
+            for game in games {
+                let best = (game.time / 2).checked_mul(game.time - game.time / 2);
+                assert!(best.is_some_and(|best| game.record < best), "{:?}", game);
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+
+use super::Rng;
+
+/// Distinct hands with bids up to 1000. Each hand is built from a randomly chosen hand type,
+/// then has up to five of its cards replaced with `J`s, so every type is covered both with
+/// and without jokers.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const TYPES: [&[usize]; 7] = [
+        &[1, 1, 1, 1, 1],
+        &[2, 1, 1, 1],
+        &[2, 2, 1],
+        &[3, 1, 1],
+        &[3, 2],
+        &[4, 1],
+        &[5],
+    ];
+
+    let mut seen = HashSet::new();
+    let mut input = String::new();
+    let mut hands = 0;
+    while hands < size {
+        let mut ranks = *b"AKQT98765432";
+        rng.shuffle(&mut ranks);
+        let mut hand: Vec<u8> = rng
+            .choose(&TYPES)
+            .iter()
+            .zip(ranks)
+            .flat_map(|(&count, rank)| std::iter::repeat_n(rank, count))
+            .collect();
+        rng.shuffle(&mut hand);
+        if rng.below(3) == 0 {
+            let jokers = rng.between(1..=5) as usize;
+            hand[..jokers].fill(b'J');
+            rng.shuffle(&mut hand);
+        }
+
+        let hand = String::from_utf8(hand).unwrap();
+        // there are only so many distinct hands, so give up on distinct ones when they run out
+        if seen.insert(hand.clone()) || seen.len() >= 13usize.pow(5) {
+            input.push_str(&format!("{} {}\n", hand, rng.between(1..=1000)));
+            hands += 1;
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::days::day7::Game;
+    use std::mem::discriminant;
+    use winnow::Parser;
+
+    #[test]
+    fn test_covers_every_hand_type() {
+        let input = generate(&mut Rng::new(0), 500);
+        for mut parse in [Game::parse_part1, Game::parse_part2] {
+            let game = parse.parse(&input).unwrap();
+            let types: HashSet<_> = game
+                .rounds
+                .iter()
+                .map(|round| discriminant(&round.hand.hand_type))
+                .collect();
+            assert_eq!(types.len(), 7);
+        }
+
+        let jokers: HashSet<usize> = input
+            .lines()
+            .map(|line| line[..5].matches('J').count())
+            .collect();
+        assert_eq!(jokers, (0..=5).collect());
+    }
+}
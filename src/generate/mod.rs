@@ -0,0 +1,145 @@
+//! Random puzzle inputs, for stress tests, benchmarks and comparing solvers with `aoc diff`.
+//!
+//! Generated inputs follow each puzzle's format, and are chosen so that every part has an
+//! answer.
+
+mod day1;
+mod day2;
+mod day4;
+mod day6;
+mod day7;
+
+/// A small seedable random number generator (SplitMix64), so that a generated input can be
+/// reproduced from its seed alone.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`. `n` must not be 0.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn between(&mut self, range: std::ops::RangeInclusive<u64>) -> u64 {
+        range.start() + self.below(range.end() - range.start() + 1)
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i as u64 + 1) as usize);
+        }
+    }
+}
+
+/// Generates random inputs for a day.
+pub struct Generator {
+    pub day: u32,
+    /// What `size` means for this day's inputs.
+    pub size: &'static str,
+    /// A size that gives an input like the real one.
+    pub default_size: usize,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+}
+
+/// A generator for every day that has one.
+pub static GENERATORS: &[Generator] = &[
+    Generator {
+        day: 1,
+        size: "lines",
+        default_size: 1000,
+        generate: day1::generate,
+    },
+    Generator {
+        day: 2,
+        size: "games",
+        default_size: 100,
+        generate: day2::generate,
+    },
+    Generator {
+        day: 4,
+        size: "cards",
+        default_size: 200,
+        generate: day4::generate,
+    },
+    Generator {
+        day: 6,
+        size: "digits in the race time for part 2",
+        default_size: 7,
+        generate: day6::generate,
+    },
+    Generator {
+        day: 7,
+        size: "hands",
+        default_size: 1000,
+        generate: day7::generate,
+    },
+];
+
+pub fn generator(day: u32) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.day == day)
+}
+
+/// Generates an input for `day` of the given size, or `None` if there's no generator for that day.
+pub fn generate(day: u32, seed: u64, size: usize) -> Option<String> {
+    generator(day).map(|generator| generator.generate(seed, size))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{days::SOLVERS, runner::Part};
+
+    #[test]
+    fn test_generate_is_reproducible() {
+        for generator in GENERATORS {
+            let input = generator.generate(7, 5);
+            assert_eq!(generator.generate(7, 5), input);
+            assert_ne!(generator.generate(8, 5), input);
+        }
+        assert_eq!(generate(3, 7, 50), None);
+    }
+
+    #[test]
+    fn test_solvers_on_generated_inputs() {
+        for solver in SOLVERS {
+            // small enough for day 6's brute force
+            let size = if solver.day == 6 { 4 } else { 50 };
+            for seed in 0..5 {
+                let Some(input) = generate(solver.day, seed, size) else {
+                    continue;
+                };
+                let answers = solver.run(&input, &Part::ALL);
+                assert!(
+                    answers.is_ok(),
+                    "{} on\n{}\n{:?}",
+                    solver.name,
+                    input,
+                    answers
+                );
+            }
+        }
+    }
+}
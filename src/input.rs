@@ -0,0 +1,120 @@
+use std::{
+    borrow::Cow,
+    fmt,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use color_eyre::{
+    eyre::{bail, Context},
+    Report, Result,
+};
+
+use crate::error::Error;
+
+/// Where to read a day's puzzle input from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// `inputs/dayNN.txt`, relative to the working directory.
+    #[default]
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads the whole input for `day`.
+    pub fn read(&self, day: u32) -> Result<String> {
+        match self {
+            InputSource::Default => {
+                let path = default_path(day);
+                if !path.exists() {
+                    bail!(Error::missing_input(path).for_day(day));
+                }
+                read_file(&path)
+            }
+            InputSource::Path(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .wrap_err("Failed to read input from stdin")?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Default => write!(f, "inputs/dayNN.txt"),
+            InputSource::Path(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "-"),
+        }
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "-" => Ok(InputSource::Stdin),
+            _ => Ok(InputSource::Path(s.into())),
+        }
+    }
+}
+
+/// The path an input for `day` is read from when no other source is given.
+pub fn default_path(day: u32) -> PathBuf {
+    Path::new("inputs").join(format!("day{:02}.txt", day))
+}
+
+/// Converts `\r\n` line endings to `\n`, and ends the last line with a newline if it doesn't
+/// have one. Inputs that are already normalized are borrowed as they are.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let mut input = Cow::Borrowed(input);
+    if input.contains('\r') {
+        input = Cow::Owned(input.replace("\r\n", "\n"));
+    }
+    if !input.is_empty() && !input.ends_with('\n') {
+        input.to_mut().push('\n');
+    }
+    input
+}
+
+/// A 64-bit FNV-1a hash of `input` in hex, to tell inputs apart in reports. It's stable across
+/// builds and platforms, but isn't cryptographic.
+pub fn hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("Failed to read input {}", path.display()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert!(matches!(normalize("a\nb\n"), Cow::Borrowed("a\nb\n")));
+        assert_eq!(normalize("a\r\nb\r\n"), "a\nb\n");
+        assert_eq!(normalize("a\nb"), "a\nb\n");
+        assert_eq!(normalize("a\r\nb"), "a\nb\n");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash(""), "cbf29ce484222325");
+        assert_eq!(hash("a"), "af63dc4c8601ec8c");
+    }
+}
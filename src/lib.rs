@@ -0,0 +1,37 @@
+use std::fmt::Display;
+
+use color_eyre::Result;
+
+pub mod answers;
+pub mod client;
+pub mod config;
+pub mod days;
+pub mod diagnostic;
+pub mod diff;
+pub mod error;
+#[cfg(test)]
+mod examples;
+pub mod generate;
+pub mod input;
+pub mod measure;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
+
+/// A solver for a single day's puzzle.
+///
+/// Parsing is split out from the two parts so that both parts can share the same parsed input,
+/// and so that tooling can time or check each phase on its own.
+pub trait Solution {
+    /// The puzzle input after it has been parsed.
+    type Parsed<'a>;
+
+    /// The type of the answer to both parts.
+    type Output: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>>;
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Output>;
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Output>;
+}
@@ -0,0 +1,97 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    time::{Duration, Instant},
+};
+
+/// A global allocator that counts the allocations made on each thread, so that [`measure`] can
+/// report them. Binaries opt in with:
+///
+/// ```
+/// # use aoc_2023::measure::CountingAllocator;
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator;
+/// # fn main() {}
+/// ```
+///
+/// Without it installed, every measurement reports zero allocations.
+pub struct CountingAllocator;
+
+thread_local! {
+    // const-initialized and without a destructor, so these are safe to touch from the allocator
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static BYTES: Cell<u64> = const { Cell::new(0) };
+}
+
+fn count(bytes: usize) {
+    ALLOCATIONS.with(|x| x.set(x.get() + 1));
+    BYTES.with(|x| x.set(x.get() + bytes as u64));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    /// Counted as a new allocation of `new_size` bytes, since that's usually what it costs.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// How long something took, and how much it allocated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Measurement {
+    pub elapsed: Duration,
+    pub allocations: u64,
+    pub bytes: u64,
+}
+
+/// Runs `f`, measuring its wall-clock time and the allocations it made on this thread.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Measurement) {
+    let allocations = ALLOCATIONS.with(Cell::get);
+    let bytes = BYTES.with(Cell::get);
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+    let measurement = Measurement {
+        elapsed,
+        allocations: ALLOCATIONS.with(Cell::get) - allocations,
+        bytes: BYTES.with(Cell::get) - bytes,
+    };
+    (result, measurement)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn test_measure_counts_allocations() {
+        let (v, measurement) = measure(|| {
+            let mut v = Vec::with_capacity(100);
+            v.push(1u8);
+            v
+        });
+        assert_eq!(v, [1]);
+        assert_eq!(measurement.allocations, 1);
+        assert_eq!(measurement.bytes, 100);
+
+        let (_, measurement) = measure(|| 1 + 1);
+        assert_eq!(measurement.allocations, 0);
+    }
+}
@@ -0,0 +1,221 @@
+use std::{
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    str::FromStr,
+};
+
+use color_eyre::{eyre::bail, Report, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    diagnostic::{self, Mode, Warning},
+    error::{self, Error},
+    input,
+    measure::{measure, Measurement},
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(into = "u8", try_from = "u8")]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> u8 {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = Report;
+
+    fn try_from(part: u8) -> Result<Self> {
+        part.to_string().parse()
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => bail!("Invalid part {}, expected 1 or 2", s),
+        }
+    }
+}
+
+/// A type-erased [`Solution`], registered under a name and the day it solves.
+pub struct Solver {
+    pub name: &'static str,
+    pub day: u32,
+    run: fn(&str, &[Part], Mode) -> Result<Run>,
+}
+
+/// The answers from a solver run, with measurements of each phase.
+#[derive(Debug)]
+pub struct Run {
+    pub parse: Measurement,
+    pub answers: Vec<Answer>,
+    /// Input ignored by a [`Mode::Lenient`] parse.
+    pub warnings: Vec<Warning>,
+}
+
+#[derive(Debug)]
+pub struct Answer {
+    pub part: Part,
+    /// The answer, or why this part failed. Each part has its own, so that one failing doesn't
+    /// hide the others' answers.
+    pub value: Result<String>,
+    pub measurement: Measurement,
+}
+
+impl Solver {
+    pub const fn new<S: Solution>(name: &'static str, day: u32) -> Self {
+        Solver {
+            name,
+            day,
+            run: run::<S>,
+        }
+    }
+
+    /// Parses `input` once in [`Mode::Strict`], then returns the answer to each of `parts` in
+    /// order.
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<Vec<String>> {
+        let run = self.measure(input, parts, Mode::Strict)?;
+        run.answers.into_iter().map(|x| x.value).collect()
+    }
+
+    /// Like [`Solver::run`], but also measures the time and allocations of each phase. Only
+    /// failing to parse is an error; each part's answer may have failed on its own.
+    pub fn measure(&self, input: &str, parts: &[Part], mode: Mode) -> Result<Run> {
+        let mut run = (self.run)(input, parts, mode).map_err(|e| error::with_day(e, self.day))?;
+        for answer in &mut run.answers {
+            answer.value = std::mem::replace(&mut answer.value, Ok(String::new()))
+                .map_err(|e| error::with_day(e, self.day));
+        }
+        Ok(run)
+    }
+}
+
+fn run<S: Solution>(input: &str, parts: &[Part], mode: Mode) -> Result<Run> {
+    let input = input::normalize(input);
+    let ((parsed, parse), warnings) =
+        diagnostic::with_mode(mode, || measure(|| catch_panic(|| S::parse(&input))));
+    let parsed = parsed?;
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            // each part catches its own panics, so that one panicking doesn't lose the other
+            let (value, measurement) = measure(|| {
+                catch_panic(|| match part {
+                    Part::One => S::part1(&parsed),
+                    Part::Two => S::part2(&parsed),
+                })
+            });
+            Answer {
+                part,
+                value: value.map(|value| value.to_string()),
+                measurement,
+            }
+        })
+        .collect();
+    Ok(Run {
+        parse,
+        answers,
+        warnings,
+    })
+}
+
+/// Calls `f`, turning a panic into an error with the panic's message.
+fn catch_panic<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            message
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message
+        } else {
+            "unknown panic"
+        };
+        Err(Error::panic(message).into())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::days::SOLVERS;
+
+    #[test]
+    fn test_part_fails_alone() -> Result<()> {
+        let day4 = SOLVERS.iter().find(|solver| solver.day == 4).unwrap();
+        let run = day4.measure(
+            "Card 1: 41 48 | 83 41\nCard 3: 41 48 | 83 41\n",
+            &Part::ALL,
+            Mode::Strict,
+        )?;
+        assert_eq!(run.answers[0].value.as_deref().ok(), Some("2"));
+        let e = run.answers[1].value.as_ref().unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Day 4: Card 3 is out of order, expected card 2"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_panics_alone() -> Result<()> {
+        struct Panicky;
+
+        impl Solution for Panicky {
+            type Parsed<'a> = &'a str;
+            type Output = u32;
+
+            fn parse(input: &str) -> Result<&str> {
+                if input.starts_with("bad") {
+                    panic!("bad input");
+                }
+                Ok(input)
+            }
+
+            fn part1(_: &&str) -> Result<u32> {
+                Ok(1)
+            }
+
+            fn part2(_: &&str) -> Result<u32> {
+                panic!("part 2 isn't {}", "done")
+            }
+        }
+        static PANICKY: Solver = Solver::new::<Panicky>("panicky", 9);
+
+        let run = PANICKY.measure("input\n", &Part::ALL, Mode::Strict)?;
+        assert_eq!(run.answers[0].value.as_deref().ok(), Some("1"));
+        let e = run.answers[1].value.as_ref().unwrap_err();
+        assert_eq!(e.to_string(), "Day 9: Panicked: part 2 isn't done");
+
+        let e = PANICKY
+            .measure("bad\n", &Part::ALL, Mode::Strict)
+            .unwrap_err();
+        assert_eq!(e.to_string(), "Day 9: Panicked: bad input");
+        Ok(())
+    }
+}
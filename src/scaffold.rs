@@ -0,0 +1,163 @@
+use std::{fs, path::Path};
+
+use color_eyre::{
+    eyre::{bail, eyre, Context},
+    Result,
+};
+
+const TEMPLATE: &str = r#"use color_eyre::Result;
+use winnow::{ascii::dec_uint, PResult, Parser};
+
+use crate::{diagnostic, error::Error, Solution};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Parsed<'a> = Vec<u64>;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        diagnostic::parse_lines(parse_line, input)
+    }
+
+    fn part1(_input: &Self::Parsed<'_>) -> Result<Self::Output> {
+        Err(Error::invalid("Part 1 isn't solved yet").into())
+    }
+
+    fn part2(_input: &Self::Parsed<'_>) -> Result<Self::Output> {
+        Err(Error::invalid("Part 2 isn't solved yet").into())
+    }
+}
+
+fn parse_line(input: &mut &str) -> PResult<u64> {
+    dec_uint.parse_next(input)
+}
+"#;
+
+const ANSWERS_TEMPLATE: &str = r#"# Replace these with the answers given in the puzzle text.
+["example.txt"]
+day = {day}
+part1 = "TODO"
+part2 = "TODO"
+"#;
+
+/// Creates everything a new day needs under the repository at `root`: a stub solution in
+/// `src/days/dayN.rs` registered with the runner, and an empty example with placeholder answers
+/// in `examples/dayNN/`, so that the day builds and its example tests fail until it's solved.
+pub fn new_day(root: &Path, day: u32) -> Result<()> {
+    let days_dir = root.join("src").join("days");
+    let registry_path = days_dir.join("mod.rs");
+    if !registry_path.exists() {
+        bail!(
+            "{} does not exist, run this from the repository root",
+            registry_path.display()
+        );
+    }
+
+    let module_path = days_dir.join(format!("day{}.rs", day));
+    if module_path.exists() {
+        bail!("{} already exists", module_path.display());
+    }
+    let examples_dir = root.join("examples").join(format!("day{:02}", day));
+    if examples_dir.exists() {
+        bail!("{} already exists", examples_dir.display());
+    }
+
+    let registry = fs::read_to_string(&registry_path)
+        .wrap_err_with(|| format!("Failed to read {}", registry_path.display()))?;
+    let registry = register(&registry, day).wrap_err_with(|| {
+        format!(
+            "Failed to register day {} in {}",
+            day,
+            registry_path.display()
+        )
+    })?;
+
+    write(&module_path, &TEMPLATE.replace("{day}", &day.to_string()))?;
+    write(&registry_path, &registry)?;
+    fs::create_dir_all(&examples_dir)
+        .wrap_err_with(|| format!("Failed to create {}", examples_dir.display()))?;
+    write(&examples_dir.join("example.txt"), "")?;
+    write(
+        &examples_dir.join("answers.toml"),
+        &ANSWERS_TEMPLATE.replace("{day}", &day.to_string()),
+    )?;
+    Ok(())
+}
+
+/// Adds `day` to the `register!` invocation in `registry`, keeping the days in order.
+fn register(registry: &str, day: u32) -> Result<String> {
+    let start = registry
+        .find("register! {\n")
+        .ok_or_else(|| eyre!("Couldn't find the register! invocation"))?
+        + "register! {\n".len();
+    let end = start
+        + registry[start..]
+            .find("\n}")
+            .ok_or_else(|| eyre!("Couldn't find the end of the register! invocation"))?
+        + 1;
+
+    // insert before the first day that comes after this one, or at the end
+    let mut position = end;
+    let mut offset = start;
+    for line in registry[start..end].split_inclusive('\n') {
+        let registered = line
+            .trim()
+            .split_once(" =>")
+            .and_then(|(x, _)| x.parse::<u32>().ok());
+        if registered.is_some_and(|x| x > day) {
+            position = offset;
+            break;
+        }
+        offset += line.len();
+    }
+
+    let mut registry = registry.to_string();
+    registry.insert_str(
+        position,
+        &format!("    {} => day{}::Day{},\n", day, day, day),
+    );
+    Ok(registry)
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).wrap_err_with(|| format!("Failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static REGISTRY: &str = "register! {\n    1 => day1::Day1,\n    7 => day7::Day7,\n}\n";
+
+    #[test]
+    fn test_register_in_order() -> Result<()> {
+        assert_eq!(
+            register(REGISTRY, 3)?,
+            "register! {\n    1 => day1::Day1,\n    3 => day3::Day3,\n    7 => day7::Day7,\n}\n"
+        );
+        assert_eq!(
+            register(REGISTRY, 8)?,
+            "register! {\n    1 => day1::Day1,\n    7 => day7::Day7,\n    8 => day8::Day8,\n}\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_day() -> Result<()> {
+        let root = tempfile::tempdir()?;
+        let days_dir = root.path().join("src").join("days");
+        fs::create_dir_all(&days_dir)?;
+        fs::write(days_dir.join("mod.rs"), REGISTRY)?;
+
+        new_day(root.path(), 9)?;
+        assert!(fs::read_to_string(days_dir.join("mod.rs"))?.contains("    9 => day9::Day9,\n}"));
+        assert!(fs::read_to_string(days_dir.join("day9.rs"))?.contains("impl Solution for Day9"));
+        let examples_dir = root.path().join("examples").join("day09");
+        assert_eq!(fs::read_to_string(examples_dir.join("example.txt"))?, "");
+        assert!(fs::read_to_string(examples_dir.join("answers.toml"))?.contains("day = 9"));
+
+        assert!(new_day(root.path(), 9).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,402 @@
+use std::{
+    fmt, fs,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use color_eyre::{eyre::Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::runner::Part;
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect {
+        hint: Option<Hint>,
+        /// How long before another answer will be accepted.
+        wait: Option<Duration>,
+    },
+    /// The answer wasn't checked, because the last one was submitted too recently.
+    TooRecent(Duration),
+    /// The part has already been solved, or isn't unlocked yet.
+    WrongLevel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the HTML page returned after submitting an answer.
+    pub fn parse(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if page.contains("That's not the right answer") {
+            let hint = if page.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if page.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            let wait = between(page, "please wait ", " before trying again").and_then(parse_wait);
+            Some(Verdict::Incorrect { hint, wait })
+        } else if page.contains("You gave an answer too recently") {
+            let wait = between(page, "You have ", " left to wait")
+                .and_then(parse_wait)
+                .unwrap_or(Duration::from_secs(60));
+            Some(Verdict::TooRecent(wait))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Verdict::WrongLevel)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Incorrect { hint, wait } => {
+                write!(f, "incorrect")?;
+                match hint {
+                    Some(Hint::TooHigh) => write!(f, ", too high")?,
+                    Some(Hint::TooLow) => write!(f, ", too low")?,
+                    None => {}
+                }
+                if let Some(wait) = wait {
+                    write!(f, " (wait {}s before trying again)", wait.as_secs())?;
+                }
+                Ok(())
+            }
+            Verdict::TooRecent(wait) => {
+                write!(f, "submitted too recently, wait {}s", wait.as_secs())
+            }
+            Verdict::WrongLevel => write!(f, "already solved, or not unlocked yet"),
+        }
+    }
+}
+
+fn between<'a>(page: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let (_, rest) = page.split_once(start)?;
+    let (middle, _) = rest.split_once(end)?;
+    Some(middle)
+}
+
+/// Parses waits as the site writes them: "1m 30s", "45s", "one minute" or "5 minutes".
+fn parse_wait(wait: &str) -> Option<Duration> {
+    if wait == "one minute" {
+        return Some(Duration::from_secs(60));
+    }
+    if let Some(minutes) = wait.strip_suffix(" minutes") {
+        return minutes
+            .parse()
+            .ok()
+            .map(|m: u64| Duration::from_secs(m * 60));
+    }
+    wait.split_whitespace()
+        .map(|x| {
+            if let Some(m) = x.strip_suffix('m') {
+                m.parse::<u64>().ok().map(|m| m * 60)
+            } else {
+                x.strip_suffix('s')?.parse::<u64>().ok()
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// A previously submitted answer, and how it was judged.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub day: u32,
+    pub part: Part,
+    pub answer: String,
+    pub correct: bool,
+    pub hint: Option<Hint>,
+}
+
+/// The record of every judged submission, so the same mistake is never sent twice.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    /// Seconds since the epoch before which the site won't accept another answer.
+    pub wait_until: Option<u64>,
+    #[serde(default, rename = "guess")]
+    pub guesses: Vec<Guess>,
+}
+
+/// Why an answer wasn't submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    AlreadyCorrect,
+    AlreadyWrong,
+    /// The answer is at least as high as one that was too high.
+    AboveBound(String),
+    /// The answer is at most as low as one that was too low.
+    BelowBound(String),
+    RateLimited(Duration),
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rejection::AlreadyCorrect => write!(f, "it was already accepted as correct"),
+            Rejection::AlreadyWrong => write!(f, "it was already submitted and was wrong"),
+            Rejection::AboveBound(bound) => write!(f, "{} was already too high", bound),
+            Rejection::BelowBound(bound) => write!(f, "{} was already too low", bound),
+            Rejection::RateLimited(wait) => {
+                write!(
+                    f,
+                    "the site is rate limiting for another {}s",
+                    wait.as_secs()
+                )
+            }
+        }
+    }
+}
+
+impl History {
+    pub const PATH: &'static str = "submissions.toml";
+
+    /// Reads the history at `path`, or an empty history if it doesn't exist.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+        let contents = fs::read_to_string(path)
+            .wrap_err_with(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&contents).wrap_err_with(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let contents = toml::to_string(self).wrap_err("Failed to serialize submissions")?;
+        fs::write(path, contents).wrap_err_with(|| format!("Failed to write {}", path.display()))
+    }
+
+    fn guesses(&self, day: u32, part: Part) -> impl Iterator<Item = &Guess> {
+        self.guesses
+            .iter()
+            .filter(move |guess| guess.day == day && guess.part == part)
+    }
+
+    /// Checks whether `answer` is worth submitting, given what's already known about the part.
+    pub fn check(&self, day: u32, part: Part, answer: &str, now: SystemTime) -> Option<Rejection> {
+        if let Some(guess) = self.guesses(day, part).find(|guess| guess.answer == answer) {
+            return Some(if guess.correct {
+                Rejection::AlreadyCorrect
+            } else {
+                Rejection::AlreadyWrong
+            });
+        }
+
+        if let Ok(value) = answer.parse::<i64>() {
+            for guess in self.guesses(day, part) {
+                let Ok(bound) = guess.answer.parse::<i64>() else {
+                    continue;
+                };
+                match guess.hint {
+                    Some(Hint::TooHigh) if value >= bound => {
+                        return Some(Rejection::AboveBound(guess.answer.clone()))
+                    }
+                    Some(Hint::TooLow) if value <= bound => {
+                        return Some(Rejection::BelowBound(guess.answer.clone()))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let now = seconds(now);
+        match self.wait_until {
+            Some(until) if until > now => {
+                Some(Rejection::RateLimited(Duration::from_secs(until - now)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Records the outcome of submitting `answer` at `now`.
+    pub fn record(
+        &mut self,
+        day: u32,
+        part: Part,
+        answer: &str,
+        verdict: Verdict,
+        now: SystemTime,
+    ) {
+        let now = seconds(now);
+        match verdict {
+            Verdict::Correct => {
+                self.wait_until = None;
+                self.guesses.push(Guess {
+                    day,
+                    part,
+                    answer: answer.to_string(),
+                    correct: true,
+                    hint: None,
+                });
+            }
+            Verdict::Incorrect { hint, wait } => {
+                self.wait_until = wait.map(|wait| now + wait.as_secs());
+                self.guesses.push(Guess {
+                    day,
+                    part,
+                    answer: answer.to_string(),
+                    correct: false,
+                    hint,
+                });
+            }
+            Verdict::TooRecent(wait) => self.wait_until = Some(now + wait.as_secs()),
+            Verdict::WrongLevel => {}
+        }
+    }
+}
+
+fn seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_verdicts() {
+        assert_eq!(
+            Verdict::parse("<p>That's the right answer!  You are one gold star closer.</p>"),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::parse(
+                "<p>That's not the right answer; your answer is too high.  If you're stuck, \
+                 [...] please wait one minute before trying again.</p>"
+            ),
+            Some(Verdict::Incorrect {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60))
+            })
+        );
+        assert_eq!(
+            Verdict::parse(
+                "<p>That's not the right answer; your answer is too low. [...] \
+                 please wait 5 minutes before trying again.</p>"
+            ),
+            Some(Verdict::Incorrect {
+                hint: Some(Hint::TooLow),
+                wait: Some(Duration::from_secs(300))
+            })
+        );
+        assert_eq!(
+            Verdict::parse(
+                "<p>You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 32s left to wait.</p>"
+            ),
+            Some(Verdict::TooRecent(Duration::from_secs(92)))
+        );
+        assert_eq!(
+            Verdict::parse(
+                "<p>You don't seem to be solving the right level.  Did you already complete it?</p>"
+            ),
+            Some(Verdict::WrongLevel)
+        );
+        assert_eq!(Verdict::parse("<html>something else</html>"), None);
+    }
+
+    #[test]
+    fn test_history_bounds() {
+        let now = UNIX_EPOCH + Duration::from_secs(1000);
+        let mut history = History::default();
+        let too_high = Verdict::Incorrect {
+            hint: Some(Hint::TooHigh),
+            wait: None,
+        };
+        let too_low = Verdict::Incorrect {
+            hint: Some(Hint::TooLow),
+            wait: None,
+        };
+        history.record(7, Part::Two, "500", too_high, now);
+        history.record(7, Part::Two, "100", too_low, now);
+
+        assert_eq!(
+            history.check(7, Part::Two, "500", now),
+            Some(Rejection::AlreadyWrong)
+        );
+        assert_eq!(
+            history.check(7, Part::Two, "600", now),
+            Some(Rejection::AboveBound("500".to_string()))
+        );
+        assert_eq!(
+            history.check(7, Part::Two, "50", now),
+            Some(Rejection::BelowBound("100".to_string()))
+        );
+        assert_eq!(history.check(7, Part::Two, "300", now), None);
+        // bounds are per day and part
+        assert_eq!(history.check(7, Part::One, "600", now), None);
+        assert_eq!(history.check(6, Part::Two, "600", now), None);
+    }
+
+    #[test]
+    fn test_history_rate_limit() {
+        let now = UNIX_EPOCH + Duration::from_secs(1000);
+        let mut history = History::default();
+        history.record(
+            1,
+            Part::One,
+            "1",
+            Verdict::TooRecent(Duration::from_secs(30)),
+            now,
+        );
+
+        assert_eq!(
+            history.check(1, Part::One, "1", now + Duration::from_secs(10)),
+            Some(Rejection::RateLimited(Duration::from_secs(20)))
+        );
+        assert_eq!(
+            history.check(1, Part::One, "1", now + Duration::from_secs(30)),
+            None
+        );
+
+        history.record(1, Part::One, "1", Verdict::Correct, now);
+        assert_eq!(
+            history.check(1, Part::One, "1", now),
+            Some(Rejection::AlreadyCorrect)
+        );
+    }
+
+    #[test]
+    fn test_history_round_trip() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("submissions.toml");
+        let now = UNIX_EPOCH + Duration::from_secs(1000);
+
+        let mut history = History::load(&path)?;
+        let verdict = Verdict::Incorrect {
+            hint: Some(Hint::TooLow),
+            wait: Some(Duration::from_secs(60)),
+        };
+        history.record(4, Part::One, "123", verdict, now);
+        history.save(&path)?;
+
+        let history = History::load(&path)?;
+        assert_eq!(history.wait_until, Some(1060));
+        assert_eq!(
+            history.guesses,
+            vec![Guess {
+                day: 4,
+                part: Part::One,
+                answer: "123".to_string(),
+                correct: false,
+                hint: Some(Hint::TooLow),
+            }]
+        );
+        Ok(())
+    }
+}